@@ -28,14 +28,42 @@ pub struct DonationV1 {
     pub referrer_fee: Option<U128>,
 }
 
+// DEPRECATED (V2)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DonationV2 {
+    /// Unique identifier for the donation
+    pub id: DonationId,
+    /// ID of the donor               
+    pub donor_id: AccountId,
+    /// Amount donated         
+    pub total_amount: u128, // changed from string to int for lower storage + consistency
+    /// FT id (e.g. "near")
+    pub ft_id: AccountId,
+    /// Optional message from the donor          
+    pub message: Option<String>,
+    /// Timestamp when the donation was made
+    pub donated_at_ms: TimestampMs,
+    /// ID of the account receiving the donation  
+    pub recipient_id: AccountId,
+    /// Protocol fee
+    pub protocol_fee: u128, // changed from string to int for lower storage + consistency
+    /// Referrer ID
+    pub referrer_id: Option<AccountId>,
+    /// Referrer fee
+    pub referrer_fee: Option<u128>, // changed from string to int for lower storage + consistency
+}
+
 // Donation is the data structure that is stored within the contract
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct Donation {
     /// Unique identifier for the donation
     pub id: DonationId,
-    /// ID of the donor               
+    /// ID of the donor (the account the donation is attributed to)
     pub donor_id: AccountId,
+    /// ID of the account that sent the funds (differs from donor_id when a trusted intermediary donates on behalf of the donor)
+    pub sent_by: AccountId,
     /// Amount donated         
     pub total_amount: u128, // changed from string to int for lower storage + consistency
     /// FT id (e.g. "near")
@@ -57,6 +85,7 @@ pub struct Donation {
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum VersionedDonation {
     V1(DonationV1),
+    V2(DonationV2),
     Current(Donation),
 }
 
@@ -65,6 +94,7 @@ impl From<VersionedDonation> for Donation {
         match donation {
             VersionedDonation::V1(v1) => Donation {
                 id: v1.id,
+                sent_by: v1.donor_id.clone(),
                 donor_id: v1.donor_id,
                 total_amount: v1.total_amount.0,
                 ft_id: v1.ft_id,
@@ -75,6 +105,19 @@ impl From<VersionedDonation> for Donation {
                 referrer_id: v1.referrer_id,
                 referrer_fee: v1.referrer_fee.map(|v| v.0),
             },
+            VersionedDonation::V2(v2) => Donation {
+                id: v2.id,
+                sent_by: v2.donor_id.clone(),
+                donor_id: v2.donor_id,
+                total_amount: v2.total_amount,
+                ft_id: v2.ft_id,
+                message: v2.message,
+                donated_at_ms: v2.donated_at_ms,
+                recipient_id: v2.recipient_id,
+                protocol_fee: v2.protocol_fee,
+                referrer_id: v2.referrer_id,
+                referrer_fee: v2.referrer_fee,
+            },
             VersionedDonation::Current(current) => current,
         }
     }
@@ -88,6 +131,8 @@ pub struct DonationExternal {
     pub id: DonationId,
     /// ID of the donor               
     pub donor_id: AccountId,
+    /// ID of the account that sent the funds
+    pub sent_by: AccountId,
     /// Amount donated         
     pub total_amount: U128,
     /// FT id (e.g. "near")
@@ -113,6 +158,7 @@ pub struct FtReceiverMsg {
     pub referrer_id: Option<AccountId>,
     pub message: Option<String>,
     pub bypass_protocol_fee: Option<bool>,
+    pub on_behalf_of: Option<AccountId>,
 }
 
 #[derive(Debug, Deserialize, Serialize, PartialEq)]
@@ -132,6 +178,7 @@ impl Contract {
         amount: U128,
         msg: String,
     ) -> PromiseOrValue<U128> {
+        self.assert_not_paused(PausableFeature::Donations);
         let ft_id = env::predecessor_account_id();
         let msg_json: FtReceiverMsg = near_sdk::serde_json::from_str(&msg)
             .expect("Invalid msg string. Must implement FtReceiverMsg.");
@@ -147,9 +194,13 @@ impl Contract {
             msg_json.bypass_protocol_fee,
         );
 
+        // attribute donation to sender, unless a trusted intermediary is donating on behalf of another account
+        let donor_id = self.resolve_donor_id(&sender_id, msg_json.on_behalf_of.clone());
+
         // create and insert donation record
         let initial_storage_usage = env::storage_usage();
         let donation = self.create_and_insert_donation_record(
+            donor_id,
             sender_id.clone(),
             amount,
             ft_id.clone(),
@@ -189,7 +240,12 @@ impl Contract {
         message: Option<String>,
         referrer_id: Option<AccountId>,
         bypass_protocol_fee: Option<bool>,
+        on_behalf_of: Option<AccountId>,
     ) -> PromiseOrValue<DonationExternal> {
+        self.assert_not_paused(PausableFeature::Donations);
+        // attribute donation to caller, unless a trusted intermediary is donating on behalf of another account
+        let sent_by = env::predecessor_account_id();
+        let donor_id = self.resolve_donor_id(&sent_by, on_behalf_of);
         // calculate amounts
         let amount = env::attached_deposit();
         let (protocol_fee, referrer_fee, mut remainder) = self.calculate_fees_and_remainder(
@@ -201,7 +257,8 @@ impl Contract {
         // create and insert donation record
         let initial_storage_usage = env::storage_usage();
         let donation = self.create_and_insert_donation_record(
-            env::predecessor_account_id(),
+            donor_id,
+            sent_by,
             U128::from(amount),
             AccountId::new_unchecked("near".to_string()),
             message,
@@ -262,6 +319,7 @@ impl Contract {
     pub(crate) fn create_and_insert_donation_record(
         &mut self,
         donor_id: AccountId,
+        sent_by: AccountId,
         total_amount: U128,
         ft_id: AccountId,
         message: Option<String>,
@@ -274,6 +332,7 @@ impl Contract {
         let donation = Donation {
             id: self.next_donation_id,
             donor_id,
+            sent_by,
             total_amount: total_amount.0,
             ft_id,
             message,
@@ -417,19 +476,19 @@ impl Contract {
         let is_ft_transfer = donation.ft_id != AccountId::new_unchecked("near".to_string());
         if call_result.is_err() {
             // ERROR CASE HANDLING
-            // 1. If donation transfer failed, delete Donation record and return all funds to donor. NB: fees have not been transferred yet.
+            // 1. If donation transfer failed, delete Donation record and return all funds to sender. NB: fees have not been transferred yet.
             // 2. If protocol fee transfer failed, update donation record to indicate protocol fee of "0". NB: donation has already been transferred to recipient and this cannot be reversed.
             // 3. If referrer fee transfer failed, update donation record to indicate referrer fee of "0". NB: donation has already been transferred to recipient and this cannot be reversed.
             match transfer_type {
                 TransferType::DonationTransfer => {
                     log!(format!(
-                        "Error transferring donation {:?} to {}. Returning funds to donor.",
+                        "Error transferring donation {:?} to {}. Returning funds to sender.",
                         donation.total_amount, donation.recipient_id
                     ));
-                    // return funds to donor
+                    // return funds to sender
                     if is_ft_transfer {
                         let donation_transfer_args =
-                            json!({ "receiver_id": donation.donor_id, "amount": U128(donation.total_amount.clone()) })
+                            json!({ "receiver_id": donation.sent_by, "amount": U128(donation.total_amount.clone()) })
                                 .to_string()
                                 .into_bytes();
                         Promise::new(AccountId::new_unchecked(donation.ft_id.to_string()))
@@ -440,34 +499,34 @@ impl Contract {
                                 Gas(XCC_GAS_DEFAULT),
                             );
                     } else {
-                        Promise::new(donation.donor_id.clone()).transfer(donation.total_amount);
+                        Promise::new(donation.sent_by.clone()).transfer(donation.total_amount);
                     }
-                    // delete donation record, and refund freed storage cost to donor's storage balance
+                    // delete donation record, and refund freed storage cost to sender's storage balance
                     let initial_storage_usage = env::storage_usage();
                     self.remove_donation_record_internal(&donation);
                     let storage_freed = initial_storage_usage - env::storage_usage();
                     let cost_freed = env::storage_byte_cost() * Balance::from(storage_freed);
-                    let storage_balance = self.storage_balance_of(&donation.donor_id);
+                    let storage_balance = self.storage_balance_of(&donation.sent_by);
                     let new_storage_balance = storage_balance.0 + cost_freed;
                     log!("Old storage balance: {}", storage_balance.0);
                     log!("New storage balance: {}", new_storage_balance);
                     self.storage_deposits
-                        .insert(&donation.donor_id, &new_storage_balance); // TODO: check if this is hackable, e.g. if user can withdraw all their storage before this callback runs and therefore get a higher refund
+                        .insert(&donation.sent_by, &new_storage_balance); // TODO: check if this is hackable, e.g. if user can withdraw all their storage before this callback runs and therefore get a higher refund
                     log!(format!(
                         "Refunded {} yoctoNEAR to {}'s storage balance for freed storage",
-                        cost_freed, donation.donor_id
+                        cost_freed, donation.sent_by
                     ));
                     None
                 }
                 TransferType::ProtocolFeeTransfer => {
                     log!(format!(
-                        "Error transferring protocol fee {:?} to {}. Returning funds to donor.",
+                        "Error transferring protocol fee {:?} to {}. Returning funds to sender.",
                         donation.protocol_fee, self.protocol_fee_recipient_account
                     ));
-                    // return funds to donor
+                    // return funds to sender
                     if is_ft_transfer {
                         let donation_transfer_args =
-                            json!({ "receiver_id": donation.donor_id, "amount": U128(donation.protocol_fee) })
+                            json!({ "receiver_id": donation.sent_by, "amount": U128(donation.protocol_fee) })
                                 .to_string()
                                 .into_bytes();
                         Promise::new(AccountId::new_unchecked(donation.ft_id.to_string()))
@@ -478,7 +537,7 @@ impl Contract {
                                 Gas(XCC_GAS_DEFAULT),
                             );
                     } else {
-                        Promise::new(donation.donor_id.clone()).transfer(donation.protocol_fee);
+                        Promise::new(donation.sent_by.clone()).transfer(donation.protocol_fee);
                     }
                     // update fee on Donation record to indicate error transferring funds
                     donation.protocol_fee = 0;
@@ -490,13 +549,13 @@ impl Contract {
                 }
                 TransferType::ReferrerFeeTransfer => {
                     log!(format!(
-                        "Error transferring referrer fee {:?} to {:?}. Returning funds to donor.",
+                        "Error transferring referrer fee {:?} to {:?}. Returning funds to sender.",
                         donation.referrer_fee, donation.referrer_id
                     ));
-                    // return funds to donor
+                    // return funds to sender
                     if is_ft_transfer {
                         let donation_transfer_args =
-                            json!({ "receiver_id": donation.donor_id, "amount": donation.referrer_fee.map(|v| U128(v)) })
+                            json!({ "receiver_id": donation.sent_by, "amount": donation.referrer_fee.map(|v| U128(v)) })
                                 .to_string()
                                 .into_bytes();
                         Promise::new(AccountId::new_unchecked(donation.ft_id.to_string()))
@@ -507,7 +566,7 @@ impl Contract {
                                 Gas(XCC_GAS_DEFAULT),
                             );
                     } else {
-                        Promise::new(donation.donor_id.clone())
+                        Promise::new(donation.sent_by.clone())
                             .transfer(donation.referrer_fee.unwrap());
                     }
                     // update fee on Donation record to indicate error transferring funds
@@ -795,6 +854,7 @@ impl Contract {
         DonationExternal {
             id: donation.id,
             donor_id: donation.donor_id.clone(),
+            sent_by: donation.sent_by.clone(),
             total_amount: U128(donation.total_amount),
             ft_id: donation.ft_id.clone(),
             message: donation.message.clone(),
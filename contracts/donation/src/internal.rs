@@ -17,4 +17,30 @@ impl Contract {
         // require owner to attach at least one yoctoNEAR for security purposes
         self.assert_at_least_one_yocto();
     }
+
+    pub(crate) fn assert_not_paused(&self, feature: PausableFeature) {
+        assert!(
+            !self.paused_features.contains(&feature),
+            "{:?} are currently paused",
+            feature
+        );
+    }
+
+    /// Returns the account a donation should be attributed to. Defaults to the account sending the funds; only trusted intermediaries may donate on behalf of another account.
+    pub(crate) fn resolve_donor_id(
+        &self,
+        sent_by: &AccountId,
+        on_behalf_of: Option<AccountId>,
+    ) -> AccountId {
+        match on_behalf_of {
+            Some(donor_id) if &donor_id != sent_by => {
+                assert!(
+                    self.trusted_intermediaries.contains(sent_by),
+                    "Only trusted intermediaries can donate on behalf of another account"
+                );
+                donor_id
+            }
+            _ => sent_by.clone(),
+        }
+    }
 }
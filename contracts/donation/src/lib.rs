@@ -12,6 +12,7 @@ pub mod donations;
 pub mod events;
 pub mod internal;
 pub mod owner;
+pub mod pause;
 pub mod source;
 pub mod storage;
 pub mod utils;
@@ -20,6 +21,7 @@ pub use crate::donations::*;
 pub use crate::events::*;
 pub use crate::internal::*;
 pub use crate::owner::*;
+pub use crate::pause::*;
 pub use crate::source::*;
 pub use crate::storage::*;
 pub use crate::utils::*;
@@ -63,6 +65,28 @@ pub struct ContractV2 {
     total_referrer_fees: Balance, // Add total_referrer_fees to track total referral fees without iterating through all donations
 }
 
+/// DEPRECATED (V3) Donation Contract
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractV3 {
+    /// Contract "source" metadata, as specified in NEP 0330 (https://github.com/near/NEPs/blob/master/neps/nep-0330.md), with addition of `commit_hash`
+    contract_source_metadata: LazyOption<VersionedContractSourceMetadata>,
+    owner: AccountId,
+    protocol_fee_basis_points: u32,
+    referral_fee_basis_points: u32,
+    protocol_fee_recipient_account: AccountId,
+    donations_by_id: UnorderedMap<DonationId, VersionedDonation>,
+    donation_ids_by_recipient_id: LookupMap<AccountId, UnorderedSet<DonationId>>,
+    donation_ids_by_donor_id: LookupMap<AccountId, UnorderedSet<DonationId>>,
+    donation_ids_by_ft_id: LookupMap<AccountId, UnorderedSet<DonationId>>,
+    total_donations_amount: Balance,
+    net_donations_amount: Balance,
+    total_protocol_fees: Balance,
+    total_referrer_fees: Balance,
+    next_donation_id: DonationId,
+    storage_deposits: UnorderedMap<AccountId, Balance>,
+}
+
 /// CURRENT Donation Contract
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize)]
@@ -83,6 +107,8 @@ pub struct Contract {
     total_referrer_fees: Balance,
     next_donation_id: DonationId, // Add next_donation_id to track next donation id and handle failed donations without accidental overwrites
     storage_deposits: UnorderedMap<AccountId, Balance>, // Add storage_deposits to track storage deposits for FTs
+    paused_features: UnorderedSet<PausableFeature>, // Add paused_features to allow owner to pause donations in an emergency
+    trusted_intermediaries: UnorderedSet<AccountId>, // Add trusted_intermediaries to allow whitelisted contracts to donate on behalf of other accounts
 }
 // #[derive(BorshSerialize, BorshDeserialize)]
 // pub enum VersionedContract {
@@ -125,6 +151,8 @@ pub struct Config {
     pub total_donations_count: U64,
     pub total_protocol_fees: U128,
     pub total_referrer_fees: U128,
+    pub paused_features: Vec<PausableFeature>,
+    pub trusted_intermediaries: Vec<AccountId>,
 }
 
 #[derive(BorshSerialize, BorshStorageKey)]
@@ -138,6 +166,8 @@ pub enum StorageKey {
     DonationIdsByFtIdInner { ft_id: AccountId },
     SourceMetadata,
     StorageDeposits,
+    PausedFeatures,
+    TrustedIntermediaries,
 }
 
 #[near_bindgen]
@@ -170,6 +200,8 @@ impl Contract {
             ),
             next_donation_id: 1,
             storage_deposits: UnorderedMap::new(StorageKey::StorageDeposits),
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
+            trusted_intermediaries: UnorderedSet::new(StorageKey::TrustedIntermediaries),
         }
     }
 
@@ -184,6 +216,8 @@ impl Contract {
             total_donations_count: self.donations_by_id.len().into(),
             total_protocol_fees: self.total_protocol_fees.into(),
             total_referrer_fees: self.total_referrer_fees.into(),
+            paused_features: self.paused_features.to_vec(),
+            trusted_intermediaries: self.trusted_intermediaries.to_vec(),
         }
     }
 
@@ -253,6 +287,32 @@ impl Contract {
     //         storage_deposits: UnorderedMap::new(StorageKey::StorageDeposits),
     //     }
     // }
+
+    // this is the initFunction used in upgrade from v3.0.0 to v4.0.0
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old_state: ContractV3 = env::state_read().expect("state read failed");
+        Self {
+            owner: old_state.owner,
+            protocol_fee_basis_points: old_state.protocol_fee_basis_points,
+            referral_fee_basis_points: old_state.referral_fee_basis_points,
+            protocol_fee_recipient_account: old_state.protocol_fee_recipient_account,
+            donations_by_id: old_state.donations_by_id,
+            donation_ids_by_recipient_id: old_state.donation_ids_by_recipient_id,
+            donation_ids_by_donor_id: old_state.donation_ids_by_donor_id,
+            donation_ids_by_ft_id: old_state.donation_ids_by_ft_id,
+            total_donations_amount: old_state.total_donations_amount,
+            net_donations_amount: old_state.net_donations_amount,
+            total_protocol_fees: old_state.total_protocol_fees,
+            total_referrer_fees: old_state.total_referrer_fees,
+            contract_source_metadata: old_state.contract_source_metadata,
+            next_donation_id: old_state.next_donation_id,
+            storage_deposits: old_state.storage_deposits,
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
+            trusted_intermediaries: UnorderedSet::new(StorageKey::TrustedIntermediaries),
+        }
+    }
 }
 
 impl Default for Contract {
@@ -282,6 +342,8 @@ impl Default for Contract {
             ),
             next_donation_id: 1,
             storage_deposits: UnorderedMap::new(StorageKey::StorageDeposits),
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
+            trusted_intermediaries: UnorderedSet::new(StorageKey::TrustedIntermediaries),
         }
     }
 }
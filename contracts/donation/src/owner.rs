@@ -45,4 +45,29 @@ impl Contract {
         self.protocol_fee_recipient_account = protocol_fee_recipient_account;
         refund_deposit(initial_storage_usage);
     }
+
+    // TRUSTED INTERMEDIARIES
+    #[payable]
+    pub fn owner_add_trusted_intermediaries(&mut self, account_ids: Vec<AccountId>) {
+        self.assert_owner();
+        let initial_storage_usage = env::storage_usage();
+        for account_id in account_ids {
+            self.trusted_intermediaries.insert(&account_id);
+        }
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn owner_remove_trusted_intermediaries(&mut self, account_ids: Vec<AccountId>) {
+        self.assert_owner();
+        let initial_storage_usage = env::storage_usage();
+        for account_id in account_ids {
+            self.trusted_intermediaries.remove(&account_id);
+        }
+        refund_deposit(initial_storage_usage);
+    }
+
+    pub fn get_trusted_intermediaries(&self) -> Vec<AccountId> {
+        self.trusted_intermediaries.to_vec()
+    }
 }
@@ -0,0 +1,42 @@
+use crate::*;
+
+/// Features of the Donation contract that can be paused independently by the owner. Withdrawal-type methods (e.g. `storage_withdraw`) are never paused.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PausableFeature {
+    /// NEAR & FT donations
+    Donations,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[payable]
+    pub fn owner_pause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_owner();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.insert(feature);
+        }
+        log_pause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn owner_unpause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_owner();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.remove(feature);
+        }
+        log_unpause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage);
+    }
+
+    pub fn get_paused_features(&self) -> Vec<PausableFeature> {
+        self.paused_features.to_vec()
+    }
+
+    pub fn is_paused(&self, feature: PausableFeature) -> bool {
+        self.paused_features.contains(&feature)
+    }
+}
@@ -5,6 +5,7 @@ impl Contract {
     #[payable]
     pub fn owner_change_owner(&mut self, list_id: ListId, new_owner_id: AccountId) -> AccountId {
         self.assert_list_owner(&list_id);
+        self.assert_not_paused(PausableFeature::Lists);
         let initial_storage_usage = env::storage_usage();
         let mut list =
             ListInternal::from(self.lists_by_id.get(&list_id).expect("List does not exist"));
@@ -36,6 +37,7 @@ impl Contract {
     #[payable]
     pub fn owner_add_admins(&mut self, list_id: ListId, admins: Vec<AccountId>) -> Vec<AccountId> {
         self.assert_list_owner(&list_id);
+        self.assert_not_paused(PausableFeature::Lists);
         let initial_storage_usage = env::storage_usage();
         let mut list_admins = self
             .list_admins_by_list_id
@@ -21,6 +21,50 @@ pub(crate) fn log_set_source_metadata_event(source_metadata: &ContractSourceMeta
     );
 }
 
+/// features paused by contract owner
+pub(crate) fn log_pause_event(paused_by: &AccountId, features: &[PausableFeature]) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "pause",
+                "data": [
+                    {
+                        "paused_by": paused_by,
+                        "features": features,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// features unpaused by contract owner
+pub(crate) fn log_unpause_event(unpaused_by: &AccountId, features: &[PausableFeature]) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "unpause",
+                "data": [
+                    {
+                        "unpaused_by": unpaused_by,
+                        "features": features,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
 /// create list event
 pub(crate) fn log_create_list_event(list: &ListExternal) {
     env::log_str(
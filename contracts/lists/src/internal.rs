@@ -8,6 +8,24 @@ impl Contract {
         );
     }
 
+    pub(crate) fn assert_contract_owner(&self) {
+        assert_eq!(
+            env::predecessor_account_id(),
+            self.contract_owner,
+            "Contract owner-only action"
+        );
+        // require owner to attach at least one yoctoNEAR for security purposes
+        self.assert_at_least_one_yocto();
+    }
+
+    pub(crate) fn assert_not_paused(&self, feature: PausableFeature) {
+        assert!(
+            !self.paused_features.contains(&feature),
+            "{:?} are currently paused",
+            feature
+        );
+    }
+
     pub(crate) fn assert_list_owner(&self, list_id: &ListId) {
         let list = ListInternal::from(self.lists_by_id.get(list_id).expect("List does not exist"));
         assert_eq!(
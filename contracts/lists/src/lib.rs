@@ -13,6 +13,7 @@ pub mod constants;
 pub mod events;
 pub mod internal;
 pub mod lists;
+pub mod pause;
 pub mod registrations;
 pub mod source;
 pub mod utils;
@@ -22,6 +23,7 @@ pub use crate::constants::*;
 pub use crate::events::*;
 pub use crate::internal::*;
 pub use crate::lists::*;
+pub use crate::pause::*;
 pub use crate::registrations::*;
 pub use crate::source::*;
 pub use crate::utils::*;
@@ -32,7 +34,25 @@ type RegistrationId = u64;
 type ListId = u64;
 type TimestampMs = u64;
 
-/// CURRENT Lists Contract (v1.0.0)
+/// OLD (v1) Lists Contract (v1.0.0)
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractV1 {
+    next_list_id: ListId,
+    next_registration_id: RegistrationId,
+    lists_by_id: UnorderedMap<ListId, VersionedList>,
+    list_ids_by_owner: UnorderedMap<AccountId, UnorderedSet<ListId>>,
+    list_ids_by_registrant: UnorderedMap<RegistrantId, UnorderedSet<ListId>>,
+    list_admins_by_list_id: LookupMap<ListId, UnorderedSet<AccountId>>,
+    registrations_by_id: UnorderedMap<RegistrationId, VersionedRegistrationInternal>,
+    registration_ids_by_list_id: UnorderedMap<ListId, UnorderedSet<RegistrationId>>,
+    registration_ids_by_registrant_id: UnorderedMap<RegistrantId, UnorderedSet<RegistrationId>>,
+    upvotes_by_list_id: LookupMap<ListId, UnorderedSet<AccountId>>,
+    upvoted_lists_by_account_id: UnorderedMap<AccountId, UnorderedSet<ListId>>,
+    contract_source_metadata: LazyOption<VersionedContractSourceMetadata>,
+}
+
+/// CURRENT Lists Contract (v2.0.0)
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
@@ -72,6 +92,10 @@ pub struct Contract {
     // blacklisted_registration_ids_by_list_id: UnorderedMap<ListId, UnorderedSet<RegistrationId>>,
     /// Contract "source" metadata
     contract_source_metadata: LazyOption<VersionedContractSourceMetadata>,
+    /// Contract superuser (distinct from list owners); can pause & unpause contract features
+    contract_owner: AccountId,
+    /// Features (lists, registrations) currently paused by the contract owner
+    paused_features: UnorderedSet<PausableFeature>,
 }
 
 #[derive(BorshSerialize, BorshStorageKey)]
@@ -98,12 +122,16 @@ pub enum StorageKey {
     // GraylistedRegistrantsByListId,
     // BlacklistedRegistrantsByListId,
     SourceMetadata,
+    PausedFeatures,
 }
 
 #[near_bindgen]
 impl Contract {
     #[init]
-    pub fn new(source_metadata: ContractSourceMetadata) -> Self {
+    pub fn new(
+        source_metadata: ContractSourceMetadata,
+        contract_owner: Option<AccountId>, // defaults to signer account if not provided
+    ) -> Self {
         Self {
             next_list_id: 1,
             next_registration_id: 1,
@@ -122,6 +150,31 @@ impl Contract {
                 StorageKey::SourceMetadata,
                 Some(&VersionedContractSourceMetadata::Current(source_metadata)),
             ),
+            contract_owner: contract_owner.unwrap_or(env::signer_account_id()),
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
+        }
+    }
+
+    // this is the initFunction used in upgrade from v1.0.0 to v2.0.0
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate(contract_owner: Option<AccountId>) -> Self {
+        let old_state: ContractV1 = env::state_read().expect("state read failed");
+        Self {
+            next_list_id: old_state.next_list_id,
+            next_registration_id: old_state.next_registration_id,
+            lists_by_id: old_state.lists_by_id,
+            list_ids_by_owner: old_state.list_ids_by_owner,
+            list_ids_by_registrant: old_state.list_ids_by_registrant,
+            list_admins_by_list_id: old_state.list_admins_by_list_id,
+            registrations_by_id: old_state.registrations_by_id,
+            registration_ids_by_list_id: old_state.registration_ids_by_list_id,
+            registration_ids_by_registrant_id: old_state.registration_ids_by_registrant_id,
+            upvotes_by_list_id: old_state.upvotes_by_list_id,
+            upvoted_lists_by_account_id: old_state.upvoted_lists_by_account_id,
+            contract_source_metadata: old_state.contract_source_metadata,
+            contract_owner: contract_owner.unwrap_or(env::signer_account_id()),
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
         }
     }
 }
@@ -60,6 +60,7 @@ impl Contract {
         default_registration_status: RegistrationStatus,
         admin_only_registrations: Option<bool>,
     ) -> ListExternal {
+        self.assert_not_paused(PausableFeature::Lists);
         let initial_storage_usage = env::storage_usage();
         assert_valid_list_name(&name);
         if let Some(description) = description.as_ref() {
@@ -129,6 +130,7 @@ impl Contract {
         admin_only_registrations: Option<bool>,
     ) -> ListExternal {
         self.assert_list_owner(&list_id);
+        self.assert_not_paused(PausableFeature::Lists);
         let initial_storage_usage = env::storage_usage();
         let mut list =
             ListInternal::from(self.lists_by_id.get(&list_id).expect("List does not exist"));
@@ -226,6 +228,7 @@ impl Contract {
 
     #[payable]
     pub fn upvote(&mut self, list_id: ListId) {
+        self.assert_not_paused(PausableFeature::Lists);
         let initial_storage_usage = env::storage_usage();
         let mut upvotes = self
             .upvotes_by_list_id
@@ -0,0 +1,54 @@
+use crate::*;
+
+/// Features of the Lists contract that can be paused independently by the contract owner. Methods that only free storage & refund deposits (e.g. `delete_list`, `unregister`, `remove_upvote`) are never paused.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PausableFeature {
+    /// Creating & updating lists (incl. list ownership & admins), and upvoting lists
+    Lists,
+    /// Registering on lists & updating registrations
+    Registrations,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[payable]
+    pub fn contract_owner_change_owner(&mut self, contract_owner: AccountId) {
+        self.assert_contract_owner();
+        self.contract_owner = contract_owner;
+    }
+
+    #[payable]
+    pub fn contract_owner_pause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_contract_owner();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.insert(feature);
+        }
+        log_pause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage, None);
+    }
+
+    #[payable]
+    pub fn contract_owner_unpause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_contract_owner();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.remove(feature);
+        }
+        log_unpause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage, None);
+    }
+
+    pub fn get_contract_owner(&self) -> AccountId {
+        self.contract_owner.clone()
+    }
+
+    pub fn get_paused_features(&self) -> Vec<PausableFeature> {
+        self.paused_features.to_vec()
+    }
+
+    pub fn is_paused(&self, feature: PausableFeature) -> bool {
+        self.paused_features.contains(&feature)
+    }
+}
@@ -220,6 +220,7 @@ impl Contract {
         notes: Option<String>, // provided by non-admin registrants
         registrations: Option<Vec<RegistrationInput>>, // provided by admin registrants
     ) -> Vec<RegistrationExternal> {
+        self.assert_not_paused(PausableFeature::Registrations);
         let list = ListInternal::from(self.lists_by_id.get(&list_id).expect("List does not exist"));
         let caller_is_admin_or_greater = self.is_caller_list_admin_or_greater(&list_id);
         let mut registrations = if caller_is_admin_or_greater {
@@ -358,6 +359,7 @@ impl Contract {
         status: Option<RegistrationStatus>,
         notes: Option<String>,
     ) -> RegistrationExternal {
+        self.assert_not_paused(PausableFeature::Registrations);
         let initial_storage_usage = env::storage_usage();
         let mut registration_internal = RegistrationInternal::from(
             self.registrations_by_id
@@ -9,15 +9,21 @@ pub struct UpdatePotArgs {
     pub owner: Option<AccountId>,
     pub admins: Option<Vec<AccountId>>,
     pub chef: Option<AccountId>,
+    pub reviewers: Option<Vec<AccountId>>,
     pub pot_name: Option<String>,
     pub pot_description: Option<String>,
     pub max_projects: Option<u32>,
     pub application_start_ms: Option<TimestampMs>,
     pub application_end_ms: Option<TimestampMs>,
+    pub application_schema: Option<Vec<ApplicationQuestion>>,
+    pub review_quorum: Option<u32>,
     pub public_round_start_ms: Option<TimestampMs>,
     pub public_round_end_ms: Option<TimestampMs>,
     pub registry_provider: Option<ProviderId>,
     pub min_matching_pool_donation_amount: Option<U128>,
+    pub donation_limits: Option<DonationLimits>,
+    pub payouts_challenge_bond: Option<U128>,
+    pub pairwise_match_threshold: Option<U128>,
     pub sybil_wrapper_provider: Option<ProviderId>,
     pub custom_sybil_checks: Option<Vec<CustomSybilCheck>>,
     pub custom_min_threshold_score: Option<u32>,
@@ -34,6 +40,8 @@ impl Contract {
         self.assert_owner();
         let initial_storage_usage = env::storage_usage();
         self.owner = owner;
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
         log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
@@ -46,6 +54,8 @@ impl Contract {
         for new_admin in admins.iter() {
             self.admins.insert(new_admin);
         }
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
         log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
@@ -57,6 +67,8 @@ impl Contract {
         for admin_to_remove in admins.iter() {
             self.admins.remove(admin_to_remove);
         }
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
         log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
@@ -69,6 +81,8 @@ impl Contract {
         for account_id in admins {
             self.admins.insert(&account_id);
         }
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
         log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
@@ -78,6 +92,8 @@ impl Contract {
         self.assert_owner();
         let initial_storage_usage = env::storage_usage();
         self.admins.clear();
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
         log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
@@ -88,6 +104,8 @@ impl Contract {
         self.assert_admin_or_greater();
         let initial_storage_usage = env::storage_usage();
         self.chef.set(&chef);
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
         log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
@@ -101,6 +119,79 @@ impl Contract {
         refund_deposit(initial_storage_usage);
     }
 
+    // REVIEWERS
+    #[payable]
+    pub fn admin_add_reviewers(&mut self, reviewers: Vec<AccountId>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for reviewer in reviewers.iter() {
+            self.reviewers.insert(reviewer);
+        }
+        self.retally_all_application_votes();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_remove_reviewers(&mut self, reviewers: Vec<AccountId>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for reviewer in reviewers.iter() {
+            self.reviewers.remove(reviewer);
+        }
+        if let Some(review_quorum) = self.review_quorum.get() {
+            assert_valid_review_quorum(review_quorum, self.reviewers.len());
+        }
+        self.retally_all_application_votes();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_set_review_quorum(&mut self, review_quorum: u32) {
+        self.assert_admin_or_greater();
+        assert_valid_review_quorum(review_quorum, self.reviewers.len());
+        let initial_storage_usage = env::storage_usage();
+        self.review_quorum.set(&review_quorum);
+        self.retally_all_application_votes();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    /// Removes the configured review quorum, reverting to a simple majority of reviewers
+    #[payable]
+    pub fn admin_remove_review_quorum(&mut self) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        self.review_quorum.remove();
+        self.retally_all_application_votes();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    /// Allows the given intermediary contracts to donate on behalf of other accounts
+    #[payable]
+    pub fn admin_add_trusted_intermediaries(&mut self, account_ids: Vec<AccountId>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for account_id in account_ids.iter() {
+            self.trusted_intermediaries.insert(account_id);
+        }
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_remove_trusted_intermediaries(&mut self, account_ids: Vec<AccountId>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for account_id in account_ids.iter() {
+            self.trusted_intermediaries.remove(account_id);
+        }
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
     #[payable]
     pub fn admin_set_chef_fee_basis_points(&mut self, chef_fee_basis_points: u32) {
         self.assert_admin_or_greater();
@@ -139,18 +230,85 @@ impl Contract {
     }
 
     #[payable]
-    pub fn admin_set_base_currency(&mut self, base_currency: AccountId) {
+    pub fn admin_set_base_currency(&mut self, base_currency: AccountId) -> PromiseOrValue<()> {
         self.assert_admin_or_greater();
-        let initial_storage_usage = env::storage_usage();
-        // only "near" allowed for now
-        // TODO: once other currencies are supported, add checks for valid FT contract
-        assert_eq!(
-            base_currency,
-            AccountId::new_unchecked("near".to_string()),
-            "Only NEAR is supported"
+        // base currency can't be changed once funds have been received
+        assert!(
+            self.donations_by_id.is_empty(),
+            "Base currency cannot be changed after donations have been made"
+        );
+        assert!(
+            !self.accepted_currencies.contains(&base_currency),
+            "{} is already an accepted currency; remove it from accepted currencies first",
+            base_currency
+        );
+        if base_currency.as_str() == NEAR_BASE_CURRENCY {
+            let initial_storage_usage = env::storage_usage();
+            self.base_currency = base_currency;
+            log_update_pot_config_event(&self.get_config());
+            refund_deposit(initial_storage_usage);
+            PromiseOrValue::Value(())
+        } else {
+            // verify that base_currency is a valid FT contract by querying its metadata
+            PromiseOrValue::Promise(
+                Promise::new(base_currency.clone())
+                    .function_call("ft_metadata".to_string(), vec![], 0, XCC_GAS)
+                    .then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(XCC_GAS)
+                            .admin_set_base_currency_callback(base_currency),
+                    ),
+            )
+        }
+    }
+
+    #[private] // Only callable by env::current_account_id()
+    pub fn admin_set_base_currency_callback(
+        &mut self,
+        base_currency: AccountId,
+        #[callback_result] call_result: Result<near_sdk::serde_json::Value, PromiseError>,
+    ) {
+        if call_result.is_err() {
+            env::panic_str(&format!(
+                "{} is not a valid NEP-141 fungible token contract",
+                base_currency
+            ));
+        }
+        assert!(
+            self.donations_by_id.is_empty(),
+            "Base currency cannot be changed after donations have been made"
         );
         self.base_currency = base_currency;
         log_update_pot_config_event(&self.get_config());
+    }
+
+    /// Sets the additional currencies accepted for public round donations (replacing any existing ones). A price oracle provider must be set unless `accepted_currencies` is empty.
+    #[payable]
+    pub fn admin_set_accepted_currencies(&mut self, accepted_currencies: Vec<AccountId>) {
+        self.assert_admin_or_greater();
+        assert_valid_accepted_currencies(
+            &accepted_currencies,
+            &self.base_currency,
+            self.price_oracle_provider.get().as_ref(),
+            self.escrow_public_donations,
+        );
+        let initial_storage_usage = env::storage_usage();
+        self.accepted_currencies.clear();
+        for currency in accepted_currencies.iter() {
+            self.accepted_currencies.insert(currency);
+        }
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_set_price_oracle_provider(&mut self, contract_id: AccountId, method_name: String) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        let provider_id = ProviderId::new(contract_id.to_string(), method_name);
+        provider_id.validate();
+        self.price_oracle_provider.set(&provider_id);
+        log_update_pot_config_event(&self.get_config());
         refund_deposit(initial_storage_usage);
     }
 
@@ -214,6 +372,45 @@ impl Contract {
         log_update_pot_config_event(&self.get_config());
     }
 
+    /// Sets the bond required for new payouts challenges (existing challenges keep the bond they were made with)
+    #[payable]
+    pub fn admin_set_payouts_challenge_bond(&mut self, payouts_challenge_bond: U128) {
+        self.assert_admin_or_greater();
+        self.payouts_challenge_bond = payouts_challenge_bond.0;
+        log_update_pot_config_event(&self.get_config());
+    }
+
+    /// Sets the pairwise match threshold used in QF calculations, restarting any calculation in progress
+    #[payable]
+    pub fn admin_set_pairwise_match_threshold(&mut self, pairwise_match_threshold: U128) {
+        self.assert_admin_or_greater();
+        assert_valid_pairwise_match_threshold(pairwise_match_threshold.0);
+        let initial_storage_usage = env::storage_usage();
+        self.pairwise_match_threshold = pairwise_match_threshold.0;
+        self.reset_qf_calculation();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_set_donation_limits(&mut self, donation_limits: DonationLimits) {
+        self.assert_admin_or_greater();
+        assert_valid_donation_limits(&donation_limits);
+        let initial_storage_usage = env::storage_usage();
+        self.donation_limits.set(&donation_limits);
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_remove_donation_limits(&mut self) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        self.donation_limits.remove();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
     #[payable]
     pub fn admin_set_sybil_wrapper_provider(
         &mut self,
@@ -241,6 +438,7 @@ impl Contract {
     #[payable]
     pub fn admin_set_custom_sybil_checks(&mut self, custom_sybil_checks: Vec<CustomSybilCheck>) {
         self.assert_admin_or_greater();
+        assert_valid_custom_sybil_checks_count(custom_sybil_checks.len());
         // TODO: validate sybil checks
         let initial_storage_usage = env::storage_usage();
         let formatted_custom_sybil_checks: HashMap<ProviderId, SybilProviderWeight> =
@@ -327,12 +525,113 @@ impl Contract {
         log_update_pot_config_event(&self.get_config());
     }
 
+    #[payable]
+    pub fn admin_set_payouts_claimable(&mut self, payouts_claimable: bool) {
+        self.assert_admin_or_greater();
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Payouts processing has already started"
+        );
+        self.payouts_claimable = payouts_claimable;
+        log_update_pot_config_event(&self.get_config());
+    }
+
+    #[payable]
+    pub fn admin_set_escrow_public_donations(&mut self, escrow_public_donations: bool) {
+        self.assert_admin_or_greater();
+        // all public donations must be handled the same way, so escrow can't be switched on/off once they have started
+        assert!(
+            self.public_round_donation_ids.is_empty(),
+            "Escrow cannot be changed after public donations have been made"
+        );
+        assert!(
+            !escrow_public_donations || self.accepted_currencies.is_empty(),
+            "Public donations cannot be escrowed when additional currencies are accepted"
+        );
+        self.escrow_public_donations = escrow_public_donations;
+        log_update_pot_config_event(&self.get_config());
+    }
+
+    #[payable]
+    pub fn admin_set_claim_deadline_ms(&mut self, claim_deadline_ms: TimestampMs) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        assert_valid_claim_deadline_ms(claim_deadline_ms, self.public_round_end_ms);
+        self.claim_deadline_ms.set(&claim_deadline_ms);
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_set_payout_vesting_schedule(
+        &mut self,
+        payout_vesting_schedule: PayoutVestingSchedule,
+    ) {
+        self.assert_admin_or_greater();
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Payouts processing has already started"
+        );
+        assert_valid_payout_vesting_schedule(&payout_vesting_schedule);
+        let initial_storage_usage = env::storage_usage();
+        self.payout_vesting_schedule.set(&payout_vesting_schedule);
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_remove_payout_vesting_schedule(&mut self) {
+        self.assert_admin_or_greater();
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Payouts processing has already started"
+        );
+        let initial_storage_usage = env::storage_usage();
+        self.payout_vesting_schedule.remove();
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_set_remainder_recipient(&mut self, remainder_recipient: AccountId) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        self.remainder_recipient.set(&remainder_recipient);
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    /// Cancels the Pot, freezing donations, applications & payouts. Matching pool donations can then be refunded to their donors (pro-rata, if some of the matching pool has already been paid out), as can any escrowed public donations that have not been released.
+    #[payable]
+    pub fn admin_cancel_pot(&mut self) {
+        self.assert_admin_or_greater();
+        assert!(!self.is_cancelled(), "Pot has already been cancelled");
+        assert!(!self.all_paid_out, "Payouts have already been processed");
+        let initial_storage_usage = env::storage_usage();
+        // matching pool donations that have not been paid out (or are not in the process of being paid out) or swept to the remainder recipient are available for refunds (including challenge bonds forfeited to the matching pool)
+        let paid_out: u128 = self
+            .payouts_by_id
+            .values()
+            .map(Payout::from)
+            .filter(|payout| payout.paid_at.is_some() && !payout.escrowed_donations)
+            // unvested remainders of terminated vesting streams are reclaimed, unless they have been swept
+            .map(|payout| payout.get_vested_amount(TimestampMs::MAX))
+            .sum::<u128>()
+            + self.swept_remainder_amount;
+        self.matching_pool_refundable_balance =
+            self.total_matching_pool_donations.saturating_sub(paid_out);
+        self.cancelled_at_ms.set(&env::block_timestamp_ms());
+        log_update_pot_config_event(&self.get_config());
+        refund_deposit(initial_storage_usage);
+    }
+
+    /// Updates admin notes on a payouts challenge and/or resolves it. An `Upheld` challenge refunds the challenger's bond & ends the cooldown, so that payouts no longer take effect (they must be cleared via `chef_clear_payouts` & set again). A `Dismissed` challenge forfeits the bond to the matching pool (or, for FT Pots, whose matching pool can't hold the NEAR bond, to the owner).
     #[payable]
     pub fn admin_update_payouts_challenge(
         &mut self,
         challenger_id: AccountId,
         notes: Option<String>,
-        resolve_challenge: Option<bool>,
+        outcome: Option<PayoutsChallengeOutcome>,
     ) {
         self.assert_admin_or_greater();
         let payouts_challenge_versioned = self.payouts_challenges.get(&challenger_id);
@@ -342,12 +641,53 @@ impl Contract {
             if let Some(notes) = notes {
                 payouts_challenge.admin_notes = Some(notes);
             }
-            payouts_challenge.resolved = resolve_challenge.unwrap_or(payouts_challenge.resolved);
+            if let Some(outcome) = outcome.clone() {
+                assert!(
+                    !payouts_challenge.resolved,
+                    "Payouts challenge has already been resolved"
+                );
+                payouts_challenge.resolved = true;
+                payouts_challenge.outcome = Some(outcome);
+            }
             self.payouts_challenges.insert(
                 &challenger_id,
-                &VersionedPayoutsChallenge::Current(payouts_challenge),
+                &VersionedPayoutsChallenge::Current(payouts_challenge.clone()),
             );
+            match outcome {
+                Some(PayoutsChallengeOutcome::Upheld) => {
+                    assert!(
+                        self.payouts_processed_index == 0 && !self.all_paid_out,
+                        "Payouts have already been processed"
+                    );
+                    // payouts no longer take effect, & must be cleared (via chef_clear_payouts) & set again (restarting the cooldown)
+                    self.payouts_draft.remove();
+                    self.cooldown_end_ms.remove();
+                    self.payouts_review_required = true;
+                    if payouts_challenge.bond > 0 {
+                        Promise::new(challenger_id.clone()).transfer(payouts_challenge.bond);
+                    }
+                }
+                Some(PayoutsChallengeOutcome::Dismissed) if payouts_challenge.bond > 0 => {
+                    if self.is_base_currency_near() {
+                        // bond is refunded pro-rata to matching pool donors if the Pot is cancelled
+                        self.forfeited_challenge_bonds += payouts_challenge.bond;
+                        self.total_matching_pool_donations += payouts_challenge.bond;
+                        self.matching_pool_balance += payouts_challenge.bond;
+                        self.reset_qf_allocation();
+                    } else {
+                        // bond is in NEAR, so cannot be added to an FT matching pool
+                        Promise::new(self.owner.clone()).transfer(payouts_challenge.bond);
+                    }
+                }
+                _ => {}
+            }
             refund_deposit(initial_storage_usage);
+            if outcome.is_some() {
+                log_challenge_resolved_event(
+                    &env::predecessor_account_id(),
+                    &payouts_challenge.to_external(challenger_id),
+                );
+            }
         }
     }
 
@@ -404,6 +744,21 @@ impl Contract {
         } else {
             self.chef.remove();
         };
+        // owner, admins & chef are not eligible for QF matching
+        self.reset_qf_allocation();
+        if let Some(reviewers) = update_args.reviewers {
+            // clear existing reviewers and reset to IDs provided
+            self.reviewers.clear();
+            for reviewer in reviewers.iter() {
+                self.reviewers.insert(reviewer);
+            }
+        }
+        if let Some(review_quorum) = update_args.review_quorum {
+            assert_valid_review_quorum(review_quorum, self.reviewers.len());
+            self.review_quorum.set(&review_quorum);
+        } else {
+            self.review_quorum.remove();
+        };
         if let Some(pot_name) = update_args.pot_name {
             assert_valid_pot_name(&pot_name);
             self.pot_name = pot_name;
@@ -435,6 +790,11 @@ impl Contract {
         if let Some(public_round_end_ms) = update_args.public_round_end_ms {
             self.public_round_end_ms = public_round_end_ms;
         }
+        if let Some(application_schema) = update_args.application_schema {
+            self.application_schema.set(&application_schema);
+        } else {
+            self.application_schema.remove();
+        };
         if let Some(registry_provider) = update_args.registry_provider {
             registry_provider.validate();
             // TODO: validate contract_id and method_name further by calling method
@@ -447,6 +807,19 @@ impl Contract {
         {
             self.min_matching_pool_donation_amount = min_matching_pool_donation_amount.0;
         }
+        if let Some(donation_limits) = update_args.donation_limits {
+            self.donation_limits.set(&donation_limits);
+        } else {
+            self.donation_limits.remove();
+        };
+        if let Some(payouts_challenge_bond) = update_args.payouts_challenge_bond {
+            self.payouts_challenge_bond = payouts_challenge_bond.0;
+        }
+        if let Some(pairwise_match_threshold) = update_args.pairwise_match_threshold {
+            assert_valid_pairwise_match_threshold(pairwise_match_threshold.0);
+            self.pairwise_match_threshold = pairwise_match_threshold.0;
+            self.reset_qf_calculation();
+        }
         if let Some(sybil_wrapper_provider) = update_args.sybil_wrapper_provider {
             sybil_wrapper_provider.validate();
             // TODO: validate contract_id and method_name further by calling method
@@ -498,6 +871,8 @@ impl Contract {
             assert_valid_chef_fee_basis_points(chef_fee_basis_points);
             self.chef_fee_basis_points = chef_fee_basis_points;
         }
+        // reviewers and/or review quorum may have changed
+        self.retally_all_application_votes();
 
         let config = self.get_config();
 
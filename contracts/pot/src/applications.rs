@@ -12,6 +12,8 @@ pub enum ApplicationStatus {
     Approved,
     Rejected,
     InReview,
+    /// Removed from matching eligibility by owner/admin after the round (see `admin_disqualify_project`); cannot be changed
+    Disqualified,
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -22,7 +24,9 @@ pub struct Application {
     pub project_id: ProjectId,
     /// Optional message to be included in application
     pub message: Option<String>,
-    /// Status of the project application (Pending, Accepted, Rejected, InReview)
+    /// Answers to the Pot's application schema questions (validated on submission)
+    pub answers: Vec<ApplicationAnswer>,
+    /// Status of the project application (Pending, Accepted, Rejected, InReview, Disqualified)
     pub status: ApplicationStatus,
     /// Timestamp for when the application was submitted
     pub submitted_at: TimestampMs,
@@ -30,6 +34,88 @@ pub struct Application {
     pub updated_at: Option<TimestampMs>,
     /// Notes to be added by Chef when reviewing the application
     pub review_notes: Option<String>,
+    /// Number of reviewers currently voting to approve the application
+    pub approve_votes_count: u32,
+    /// Number of reviewers currently voting to reject the application
+    pub reject_votes_count: u32,
+    /// Category (e.g. track) assigned by chef/admin, used to determine eligibility for earmarked matching sub-pools
+    pub category: Option<String>,
+    /// Reason given by owner/admin for disqualifying the project. None if not disqualified.
+    pub disqualification_reason: Option<String>,
+    /// Account that the project's payouts are transferred to (e.g. a multisig or DAO treasury). Payouts go to `project_id` if None.
+    pub payout_recipient: Option<AccountId>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ReviewVote {
+    Approve,
+    Reject,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApplicationVote {
+    /// Reviewer that cast the vote
+    pub reviewer_id: AccountId,
+    pub vote: ReviewVote,
+    /// Optional notes from the reviewer
+    pub notes: Option<String>,
+    /// Timestamp for when the vote was (last) cast
+    pub voted_at: TimestampMs,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApplicationReview {
+    /// Account (chef, admin or owner) that changed the application status, or reviewer whose vote reached quorum
+    pub reviewer_id: AccountId,
+    /// Status of the application before this review
+    pub previous_status: ApplicationStatus,
+    /// Status of the application after this review
+    pub status: ApplicationStatus,
+    /// Notes provided by the reviewer
+    pub notes: String,
+    /// Timestamp for when the review was made
+    pub reviewed_at: TimestampMs,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ApplicationQuestionType {
+    Text,
+    Number,
+    Boolean,
+    Url,
+    AccountId,
+    /// Answer must be one of the provided options
+    Select {
+        options: Vec<String>,
+    },
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApplicationQuestion {
+    /// Unique identifier for the question, referenced by answers
+    pub key: String,
+    /// Question to be displayed to applicants
+    pub prompt: String,
+    /// Type of answer expected
+    pub question_type: ApplicationQuestionType,
+    /// Whether an answer must be provided
+    pub required: bool,
+    /// Maximum length of answer (in bytes). Defaults to MAX_APPLICATION_ANSWER_LENGTH.
+    pub max_length: Option<u32>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApplicationAnswer {
+    /// Key of the question being answered
+    pub key: String,
+    /// Answer, as a string (e.g. "true" for a Boolean question, "42" for a Number question)
+    pub value: String,
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -58,13 +144,23 @@ impl From<&VersionedApplication> for Application {
 #[near_bindgen]
 impl Contract {
     #[payable]
-    pub fn apply(&mut self, message: Option<String>) -> PromiseOrValue<Application> {
+    pub fn apply(
+        &mut self,
+        message: Option<String>,
+        answers: Option<Vec<ApplicationAnswer>>,
+        payout_recipient: Option<AccountId>,
+    ) -> PromiseOrValue<Application> {
+        self.assert_not_paused(PausableFeature::Applications);
         let project_id = env::predecessor_account_id(); // TODO: consider renaming to "applicant_id" to make it less opinionated (e.g. maybe developers are applying, and they are not exactly a "project")
                                                         // chef, admin & owner cannot apply
         assert!(
             !self.is_chef(Some(&project_id)) && !self.is_owner_or_admin(Some(&project_id)),
             "Chef, admin & owner cannot apply"
         );
+        self.assert_valid_payout_recipient(payout_recipient.as_ref());
+        // validate answers before making any cross-contract calls
+        let answers = answers.unwrap_or_default();
+        self.assert_valid_application_answers(&answers);
         let deposit = env::attached_deposit();
         if let Some(registry_provider) = self.registry_provider.get() {
             // decompose registry provider
@@ -77,11 +173,23 @@ impl Contract {
                     .then(
                         Self::ext(env::current_account_id())
                             .with_static_gas(XCC_GAS)
-                            .assert_can_apply_callback(project_id.clone(), message, deposit),
+                            .assert_can_apply_callback(
+                                project_id.clone(),
+                                message,
+                                answers,
+                                payout_recipient,
+                                deposit,
+                            ),
                     ),
             )
         } else {
-            PromiseOrValue::Value(self.handle_apply(project_id, message, deposit))
+            PromiseOrValue::Value(self.handle_apply(
+                project_id,
+                message,
+                answers,
+                payout_recipient,
+                deposit,
+            ))
         }
     }
 
@@ -90,6 +198,8 @@ impl Contract {
         &mut self,
         project_id: ProjectId,
         message: Option<String>,
+        answers: Vec<ApplicationAnswer>,
+        payout_recipient: Option<AccountId>,
         deposit: Balance,
         #[callback_result] call_result: Result<bool, PromiseError>,
     ) -> Application {
@@ -100,7 +210,7 @@ impl Contract {
                 self.registry_provider.get().unwrap()
             ));
         }
-        self.handle_apply(project_id, message, deposit)
+        self.handle_apply(project_id, message, answers, payout_recipient, deposit)
     }
 
     #[private]
@@ -108,6 +218,8 @@ impl Contract {
         &mut self,
         project_id: ProjectId,
         message: Option<String>,
+        answers: Vec<ApplicationAnswer>,
+        payout_recipient: Option<AccountId>,
         deposit: Balance,
     ) -> Application {
         // check that application doesn't already exist for this project
@@ -117,14 +229,22 @@ impl Contract {
         }
         // check that application period is open
         self.assert_application_period_open();
+        // check that Pot has not been cancelled
+        self.assert_not_cancelled();
         // add application
         let application = Application {
             project_id,
             message,
+            answers,
             status: ApplicationStatus::Pending,
             submitted_at: env::block_timestamp_ms(),
             updated_at: None,
             review_notes: None,
+            approve_votes_count: 0,
+            reject_votes_count: 0,
+            category: None,
+            disqualification_reason: None,
+            payout_recipient,
         };
         // charge for storage
         let initial_storage_usage = env::storage_usage();
@@ -143,6 +263,7 @@ impl Contract {
                 required_deposit
             ));
         }
+        log_apply_event(&application);
 
         // return application
         application
@@ -165,10 +286,61 @@ impl Contract {
         );
         // get current storage usage
         let initial_storage_usage = env::storage_usage();
-        // remove from mappings
+        // remove from mappings, including any votes (so that they don't count towards a new application, should the project apply again). Review history is append-only, so it is kept.
         self.applications_by_id.remove(&project_id);
+        if let Some(mut votes) = self.application_votes_by_project_id.remove(&project_id) {
+            votes.clear();
+        }
         // refund for storage freed
         refund_deposit(initial_storage_usage);
+        log_unapply_event(&project_id);
+    }
+
+    /// Sets (or removes, if `payout_recipient` is None) the account that the caller's payouts are transferred to. Can only be changed until payouts have been set. Chef, admin & owner cannot be payout recipients.
+    #[payable]
+    pub fn update_payout_recipient(&mut self, payout_recipient: Option<AccountId>) -> Application {
+        self.assert_not_paused(PausableFeature::Payouts);
+        let project_id = env::predecessor_account_id();
+        let mut application = Application::from(
+            self.applications_by_id
+                .get(&project_id)
+                .expect("Application does not exist for calling project"),
+        );
+        assert!(
+            self.payouts_by_id.is_empty(),
+            "Payout recipient cannot be changed once payouts have been set"
+        );
+        self.assert_valid_payout_recipient(payout_recipient.as_ref());
+        let initial_storage_usage = env::storage_usage();
+        application.payout_recipient = payout_recipient;
+        application.updated_at = Some(env::block_timestamp_ms());
+        self.applications_by_id.insert(
+            &project_id,
+            &VersionedApplication::Current(application.clone()),
+        );
+        log_update_payout_recipient_event(&project_id, &application.payout_recipient);
+        refund_deposit(initial_storage_usage);
+        application
+    }
+
+    /// As with applicants, chef, admin & owner cannot receive a project's payouts
+    pub(crate) fn assert_valid_payout_recipient(&self, payout_recipient: Option<&AccountId>) {
+        if let Some(payout_recipient) = payout_recipient {
+            assert!(
+                !self.is_chef(Some(payout_recipient))
+                    && !self.is_owner_or_admin(Some(payout_recipient)),
+                "Chef, admin & owner cannot be payout recipients"
+            );
+        }
+    }
+
+    /// Account that a project's payouts should be transferred to (the project itself, unless it has set a payout recipient)
+    pub(crate) fn get_payout_recipient(&self, project_id: &ProjectId) -> AccountId {
+        self.applications_by_id
+            .get(project_id)
+            .map(Application::from)
+            .and_then(|application| application.payout_recipient)
+            .unwrap_or(project_id.clone())
     }
 
     pub fn get_applications(
@@ -238,6 +410,62 @@ impl Contract {
         )
     }
 
+    pub fn get_application_review_history(
+        &self,
+        project_id: ProjectId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<ApplicationReview> {
+        // review history is kept if the application has been withdrawn
+        let Some(reviews) = self.application_reviews_by_project_id.get(&project_id) else {
+            assert!(
+                self.applications_by_id.get(&project_id).is_some(),
+                "Application does not exist"
+            );
+            return vec![];
+        };
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            reviews.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(usize::MAX as u64);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        reviews
+            .iter()
+            .skip(start_index as usize)
+            .take(limit.try_into().unwrap())
+            .collect()
+    }
+
+    /// Votes cast by reviewers on a project's application (including votes of accounts that are no longer reviewers, which are not counted)
+    pub fn get_application_votes(
+        &self,
+        project_id: ProjectId,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<ApplicationVote> {
+        assert!(
+            self.applications_by_id.get(&project_id).is_some(),
+            "Application does not exist"
+        );
+        let Some(votes) = self.application_votes_by_project_id.get(&project_id) else {
+            return vec![];
+        };
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            votes.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(usize::MAX as u64);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        votes
+            .values()
+            .skip(start_index as usize)
+            .take(limit.try_into().unwrap())
+            .collect()
+    }
+
     #[payable]
     pub fn chef_set_application_status(
         &mut self,
@@ -246,17 +474,293 @@ impl Contract {
         notes: String,
     ) -> Application {
         self.assert_chef_or_greater();
+        self.assert_not_cancelled();
+        self.assert_not_paused(PausableFeature::Applications);
         // verify that the application exists
+        let application = Application::from(
+            self.applications_by_id
+                .get(&project_id)
+                .expect("Application does not exist"),
+        );
+        assert!(
+            application.status != ApplicationStatus::Disqualified,
+            "Project has been disqualified; application status cannot be changed"
+        );
+        assert!(
+            status != ApplicationStatus::Disqualified,
+            "Use admin_disqualify_project to disqualify a project"
+        );
+        // caller pays for the review appended to the application's review history
+        let initial_storage_usage = env::storage_usage();
+        let application = self.set_application_status_internal(application, status, notes);
+        refund_deposit(initial_storage_usage);
+        application
+    }
+
+    /// Disqualifies an approved project once the round has closed (callable by admin or owner), e.g. if fraud is discovered. The project is removed from matching eligibility & the reason is recorded on its application.
+    /// Any QF calculation is restarted without the project. If payouts have already been set, the project's payouts (matching funds & escrowed donations) are removed & an `adjust_payouts` event is emitted with the reason as justification. The remaining payouts are flagged for review by the chef (`payouts_review_required`), and the cooldown restarts.
+    #[payable]
+    pub fn admin_disqualify_project(
+        &mut self,
+        project_id: ProjectId,
+        reason: String,
+    ) -> Application {
+        self.assert_admin_or_greater();
+        self.assert_not_cancelled();
+        self.assert_round_closed();
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Cannot disqualify a project once payouts processing has started"
+        );
+        let reason = reason.trim().to_string();
+        assert!(
+            !reason.is_empty(),
+            "Reason for disqualification must be provided"
+        );
+        let application = Application::from(
+            self.applications_by_id
+                .get(&project_id)
+                .expect("Application does not exist"),
+        );
+        assert!(
+            application.status == ApplicationStatus::Approved,
+            "Only approved projects can be disqualified"
+        );
+        let initial_storage_usage = env::storage_usage();
+        let mut application = self.set_application_status_internal(
+            application,
+            ApplicationStatus::Disqualified,
+            reason.clone(),
+        );
+        application.disqualification_reason = Some(reason.clone());
+        self.applications_by_id.insert(
+            &project_id,
+            &VersionedApplication::Current(application.clone()),
+        );
+        // payouts that are still being set no longer add up, so must be cleared (via chef_clear_payouts) & set again
+        let payouts_draft_discarded = self.payouts_draft.get().is_some();
+        if payouts_draft_discarded {
+            self.payouts_draft.remove();
+        }
+        // remove the project's payouts (if set), leaving the rest for the chef to review
+        if !self.payouts_by_id.is_empty() {
+            self.remove_project_payouts(&project_id);
+            self.payouts_review_required = true;
+            // cooldown restarts so that the remaining (finalized) payouts can be reviewed (& challenged) again
+            if !payouts_draft_discarded {
+                self.cooldown_end_ms
+                    .set(&(env::block_timestamp_ms() + self.cooldown_period_ms));
+            }
+            log_adjust_payouts_event(
+                &env::predecessor_account_id(),
+                &reason,
+                &[PayoutAdjustment {
+                    project_id: project_id.clone(),
+                    calculated_amount: None,
+                    amount: U128(0),
+                }],
+            );
+        }
+        log_disqualify_project_event(&env::predecessor_account_id(), &project_id, &reason);
+        refund_deposit(initial_storage_usage);
+        application
+    }
+
+    /// Assigns a category to a project's application (or removes it, if `category` is None). Projects in a category are eligible for matching sub-pools earmarked for that category.
+    #[payable]
+    pub fn chef_set_application_category(
+        &mut self,
+        project_id: ProjectId,
+        category: Option<String>,
+    ) -> Application {
+        self.assert_chef_or_greater();
+        self.assert_not_cancelled();
+        self.assert_not_paused(PausableFeature::Applications);
         let mut application = Application::from(
             self.applications_by_id
                 .get(&project_id)
                 .expect("Application does not exist"),
         );
+        let category = category.map(|category| category.trim().to_string());
+        if let Some(category) = category.as_ref() {
+            assert_valid_category(category);
+        }
+        let initial_storage_usage = env::storage_usage();
+        application.category = category;
+        application.updated_at = Some(env::block_timestamp_ms());
+        self.applications_by_id.insert(
+            &project_id,
+            &VersionedApplication::Current(application.clone()),
+        );
+        // categories determine eligibility for earmarked sub-pools
+        self.reset_qf_allocation();
+        log_set_application_category_event(&project_id, &application.category);
+        refund_deposit(initial_storage_usage);
+        application
+    }
+
+    /// Records a reviewer's vote on a pending or in-review application. Once `review_quorum` approve (or reject) votes are reached, the application is approved (or rejected) automatically.
+    #[payable]
+    pub fn reviewer_vote_on_application(
+        &mut self,
+        project_id: ProjectId,
+        vote: ReviewVote,
+        notes: Option<String>,
+    ) -> Application {
+        let reviewer_id = env::predecessor_account_id();
+        assert!(
+            self.reviewers.contains(&reviewer_id),
+            "Only reviewers can call this method"
+        );
+        assert_ne!(
+            reviewer_id, project_id,
+            "Reviewers cannot vote on their own application"
+        );
+        self.assert_at_least_one_yocto();
+        self.assert_not_cancelled();
+        self.assert_not_paused(PausableFeature::Applications);
+        let application = Application::from(
+            self.applications_by_id
+                .get(&project_id)
+                .expect("Application does not exist"),
+        );
+        assert!(
+            application.status == ApplicationStatus::Pending
+                || application.status == ApplicationStatus::InReview,
+            "Application status is {:?}. Only pending or in-review applications can be voted on",
+            application.status
+        );
+        let initial_storage_usage = env::storage_usage();
+        // replace any previous vote by this reviewer
+        let mut votes = self
+            .application_votes_by_project_id
+            .get(&project_id)
+            .unwrap_or_else(|| {
+                UnorderedMap::new(StorageKey::ApplicationVotesByProjectIdInner {
+                    project_id: project_id.clone(),
+                })
+            });
+        let application_vote = ApplicationVote {
+            reviewer_id: reviewer_id.clone(),
+            vote: vote.clone(),
+            notes,
+            voted_at: env::block_timestamp_ms(),
+        };
+        votes.insert(&reviewer_id, &application_vote);
+        self.application_votes_by_project_id
+            .insert(&project_id, &votes);
+        log_application_vote_event(&project_id, &application_vote);
+        let application = self.tally_application_votes(application);
+        refund_deposit(initial_storage_usage);
+        application
+    }
+
+    /// Recounts the application's votes (only votes of current reviewers are counted) & stores the updated application. A pending or in-review application is approved or rejected once `review_quorum` matching votes are reached (unless approving it would exceed `max_projects`, in which case it is left for the chef).
+    pub(crate) fn tally_application_votes(&mut self, mut application: Application) -> Application {
+        let (approve_votes_count, reject_votes_count) = self
+            .application_votes_by_project_id
+            .get(&application.project_id)
+            .map(|votes| self.count_votes(&votes))
+            .unwrap_or_default();
+        application.approve_votes_count = approve_votes_count;
+        application.reject_votes_count = reject_votes_count;
+        let is_open = application.status == ApplicationStatus::Pending
+            || application.status == ApplicationStatus::InReview;
+        let review_quorum = self.get_review_quorum();
+        if is_open
+            && approve_votes_count >= review_quorum
+            && self.approved_application_ids.len() < self.max_projects.into()
+        {
+            self.set_application_status_internal(
+                application,
+                ApplicationStatus::Approved,
+                format!("Approved by {} reviewer votes", approve_votes_count),
+            )
+        } else if is_open && reject_votes_count >= review_quorum {
+            self.set_application_status_internal(
+                application,
+                ApplicationStatus::Rejected,
+                format!("Rejected by {} reviewer votes", reject_votes_count),
+            )
+        } else {
+            self.applications_by_id.insert(
+                &application.project_id,
+                &VersionedApplication::Current(application.clone()),
+            );
+            application
+        }
+    }
+
+    /// Re-tallies votes on every application that has been voted on, after the reviewer panel or review quorum has changed
+    pub(crate) fn retally_all_application_votes(&mut self) {
+        if self.is_cancelled() {
+            return;
+        }
+        let applications: Vec<Application> = self
+            .applications_by_id
+            .values()
+            .map(Application::from)
+            .filter(|application| {
+                self.application_votes_by_project_id
+                    .contains_key(&application.project_id)
+            })
+            .collect();
+        for application in applications {
+            self.tally_application_votes(application);
+        }
+    }
+
+    pub(crate) fn count_votes(
+        &self,
+        votes: &UnorderedMap<AccountId, ApplicationVote>,
+    ) -> (u32, u32) {
+        votes
+            .values()
+            .filter(|vote| self.reviewers.contains(&vote.reviewer_id))
+            .fold((0, 0), |(approve, reject), vote| match vote.vote {
+                ReviewVote::Approve => (approve + 1, reject),
+                ReviewVote::Reject => (approve, reject + 1),
+            })
+    }
+
+    /// Number of matching reviewer votes required to approve or reject an application (defaults to a simple majority of reviewers)
+    pub(crate) fn get_review_quorum(&self) -> u32 {
+        self.review_quorum
+            .get()
+            .unwrap_or(self.reviewers.len() as u32 / 2 + 1)
+    }
+
+    /// Updates application status (logging the review in the application's review history, stored separately from the application) & approved applications mapping
+    pub(crate) fn set_application_status_internal(
+        &mut self,
+        mut application: Application,
+        status: ApplicationStatus,
+        notes: String,
+    ) -> Application {
+        let project_id = application.project_id.clone();
         // update application
         let previous_status = application.status.clone();
+        let review = ApplicationReview {
+            reviewer_id: env::predecessor_account_id(),
+            previous_status: previous_status.clone(),
+            status: status.clone(),
+            notes: notes.clone(),
+            reviewed_at: env::block_timestamp_ms(),
+        };
         application.status = status;
-        application.updated_at = Some(env::block_timestamp_ms());
+        application.updated_at = Some(review.reviewed_at);
         application.review_notes = Some(notes);
+        let mut reviews = self
+            .application_reviews_by_project_id
+            .get(&project_id)
+            .unwrap_or_else(|| {
+                Vector::new(StorageKey::ApplicationReviewsByProjectIdInner {
+                    project_id: project_id.clone(),
+                })
+            });
+        reviews.push(&review);
+        self.application_reviews_by_project_id
+            .insert(&project_id, &reviews);
         // update mapping
         self.applications_by_id.insert(
             &project_id,
@@ -266,13 +770,17 @@ impl Contract {
         if application.status == ApplicationStatus::Approved {
             // check that max_projects hasn't been reached
             self.assert_max_projects_not_reached();
-            self.approved_application_ids.insert(&project_id);
+            if self.approved_application_ids.insert(&project_id) {
+                self.reset_qf_calculation();
+            }
         } else {
             // setting application status as something other than Approved; if it was previously approved, remove from approved mapping
             if previous_status == ApplicationStatus::Approved {
                 self.approved_application_ids.remove(&project_id);
+                self.reset_qf_calculation();
             }
         }
+        log_update_application_status_event(&project_id, &review);
         application
     }
 
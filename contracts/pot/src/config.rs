@@ -7,17 +7,25 @@ pub struct PotConfig {
     pub owner: AccountId,
     pub admins: Vec<AccountId>,
     pub chef: Option<AccountId>,
+    pub reviewers: Vec<AccountId>,
     pub pot_name: String,
     pub pot_description: String,
     pub max_projects: u32,
     pub base_currency: AccountId,
+    pub accepted_currencies: Vec<AccountId>,
+    pub price_oracle_provider: Option<ProviderId>,
     pub application_start_ms: TimestampMs,
     pub application_end_ms: TimestampMs,
+    pub application_schema: Option<Vec<ApplicationQuestion>>,
+    pub review_quorum: Option<u32>,
     pub public_round_start_ms: TimestampMs,
     pub public_round_end_ms: TimestampMs,
     pub deployed_by: AccountId,
     pub registry_provider: Option<ProviderId>,
     pub min_matching_pool_donation_amount: U128,
+    pub donation_limits: Option<DonationLimits>,
+    pub escrow_public_donations: bool,
+    pub pairwise_match_threshold: U128,
     pub sybil_wrapper_provider: Option<ProviderId>,
     pub custom_sybil_checks: Option<HashMap<ProviderId, SybilProviderWeight>>,
     pub custom_min_threshold_score: Option<u32>,
@@ -27,30 +35,57 @@ pub struct PotConfig {
     pub matching_pool_balance: U128,
     pub total_public_donations: U128,
     pub public_donations_count: u32,
+    pub escrowed_donations_balance: U128,
     pub payouts: Vec<PayoutExternal>,
+    pub pending_payouts_count: u32,
+    pub succeeded_payouts_count: u32,
+    pub failed_payouts_count: u32,
     pub cooldown_end_ms: Option<TimestampMs>,
+    pub payouts_challenge_bond: U128,
+    pub forfeited_challenge_bonds: U128,
+    pub reclaimed_payouts_balance: U128,
+    pub swept_remainder_amount: U128,
+    pub payouts_review_required: bool,
+    pub payouts_claimable: bool,
+    pub claim_deadline_ms: Option<TimestampMs>,
+    pub payout_vesting_schedule: Option<PayoutVestingSchedule>,
+    pub payouts_processed_index: u64,
     pub all_paid_out: bool,
+    pub remainder_recipient: Option<AccountId>,
+    pub cancelled_at_ms: Option<TimestampMs>,
+    pub paused_features: Vec<PausableFeature>,
+    pub trusted_intermediaries: Vec<AccountId>,
     pub protocol_config_provider: Option<ProviderId>,
 }
 
 #[near_bindgen]
 impl Contract {
     pub fn get_config(&self) -> PotConfig {
+        let (pending_payouts_count, succeeded_payouts_count, failed_payouts_count) =
+            self.get_payouts_counts();
         PotConfig {
             owner: self.owner.clone(),
             admins: self.admins.to_vec(),
             chef: self.chef.get(),
+            reviewers: self.reviewers.to_vec(),
             pot_name: self.pot_name.clone(),
             pot_description: self.pot_description.clone(),
             max_projects: self.max_projects,
             base_currency: self.base_currency.clone(),
+            accepted_currencies: self.accepted_currencies.to_vec(),
+            price_oracle_provider: self.price_oracle_provider.get(),
             application_start_ms: self.application_start_ms,
             application_end_ms: self.application_end_ms,
+            application_schema: self.application_schema.get(),
+            review_quorum: self.review_quorum.get(),
             public_round_start_ms: self.public_round_start_ms,
             public_round_end_ms: self.public_round_end_ms,
             deployed_by: self.deployed_by.clone(),
             registry_provider: self.registry_provider.get(),
             min_matching_pool_donation_amount: self.min_matching_pool_donation_amount.into(),
+            donation_limits: self.donation_limits.get(),
+            escrow_public_donations: self.escrow_public_donations,
+            pairwise_match_threshold: self.pairwise_match_threshold.into(),
             sybil_wrapper_provider: self.sybil_wrapper_provider.get(),
             custom_sybil_checks: self.custom_sybil_checks.get(),
             custom_min_threshold_score: self.custom_min_threshold_score.get(),
@@ -60,9 +95,26 @@ impl Contract {
             matching_pool_balance: self.matching_pool_balance.into(),
             total_public_donations: self.total_public_donations.into(),
             public_donations_count: self.public_round_donation_ids.len() as u32,
+            escrowed_donations_balance: self.get_total_escrowed_donations().into(),
             payouts: self.get_payouts(None, None),
+            pending_payouts_count,
+            succeeded_payouts_count,
+            failed_payouts_count,
             cooldown_end_ms: self.cooldown_end_ms.get(),
+            payouts_challenge_bond: self.payouts_challenge_bond.into(),
+            forfeited_challenge_bonds: self.forfeited_challenge_bonds.into(),
+            reclaimed_payouts_balance: self.reclaimed_payouts_balance.into(),
+            swept_remainder_amount: self.swept_remainder_amount.into(),
+            payouts_review_required: self.payouts_review_required,
+            payouts_claimable: self.payouts_claimable,
+            claim_deadline_ms: self.claim_deadline_ms.get(),
+            payout_vesting_schedule: self.payout_vesting_schedule.get(),
+            payouts_processed_index: self.payouts_processed_index,
             all_paid_out: self.all_paid_out,
+            remainder_recipient: self.remainder_recipient.get(),
+            cancelled_at_ms: self.cancelled_at_ms.get(),
+            paused_features: self.paused_features.to_vec(),
+            trusted_intermediaries: self.trusted_intermediaries.to_vec(),
             protocol_config_provider: self.protocol_config_provider.get(),
         }
     }
@@ -4,15 +4,34 @@ pub const ONE_DAY_MS: u64 = 86_400_000;
 pub const ONE_WEEK_MS: u64 = ONE_DAY_MS * 7;
 pub const TGAS: u64 = 1_000_000_000_000;
 pub const XCC_GAS: Gas = Gas(TGAS * 5);
+pub const FT_TRANSFER_GAS: Gas = Gas(TGAS * 10);
+pub const CUSTOM_SYBIL_CHECK_GAS: Gas = Gas(TGAS * 10);
+pub const ROLLOVER_GAS: Gas = Gas(TGAS * 100); // donation into another Pot's matching pool (incl. protocol fee XCC)
+pub const SWEEP_REMAINDER_CALLBACK_GAS: Gas = Gas(TGAS * 150);
+pub const ONE_YOCTO: Balance = 1;
+pub const NEAR_BASE_CURRENCY: &str = "near";
 pub const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
 
 // Pot args constraints
 pub const MAX_POT_NAME_LENGTH: usize = 64;
 pub const MAX_POT_DESCRIPTION_LENGTH: usize = 256;
-pub const MAX_MAX_PROJECTS: u32 = 100; // TODO: figure out actual limit based on gas
+pub const MAX_APPLICATION_QUESTIONS: usize = 20;
+pub const MAX_APPLICATION_QUESTION_PROMPT_LENGTH: usize = 512;
+pub const MAX_APPLICATION_ANSWER_LENGTH: u32 = 2048; // also the default max length for answers if question doesn't specify one
+pub const MAX_MAX_PROJECTS: u32 = 1000; // setting, clearing & processing payouts and the QF calculation are all batched, so this is only bounded by storage
 pub const MAX_REFERRAL_FEE_MATCHING_POOL_BASIS_POINTS: u32 = 1000; // 10%
 pub const MAX_REFERRAL_FEE_PUBLIC_ROUND_BASIS_POINTS: u32 = 1000; // 10%
 pub const MAX_CHEF_FEE_BASIS_POINTS: u32 = 1000; // 10%
+pub const MAX_DONATIONS_PER_BATCH: usize = 20; // all donations in a batch are processed (and transferred) in a single receipt, so keep within gas limits
+pub const MAX_MATCHING_SUB_POOLS: usize = 8; // payouts are validated against every combination of sub-pools, so keep this small
+pub const MAX_CATEGORY_LENGTH: usize = 32;
+pub const MAX_CUSTOM_SYBIL_CHECKS: usize = 10; // each check is queried in parallel on public donations, so keep within gas limits
+pub const MAX_ACCEPTED_CURRENCIES: usize = 10; // in addition to the base currency
+pub const MAX_PRICE_DECIMALS: u8 = 38; // 10^decimals must fit in a u128
 pub const MAX_PROTOCOL_FEE_BASIS_POINTS: u32 = 1000; // 10%
 pub const MIN_COOLDOWN_PERIOD_MS: u64 = ONE_WEEK_MS;
 pub const DEFAULT_COOLDOWN_PERIOD_MS: u64 = ONE_WEEK_MS;
+pub const DEFAULT_PAYOUTS_CHALLENGE_BOND: Balance = 1_000_000_000_000_000_000_000_000; // 1 NEAR
+pub const DEFAULT_PAIRWISE_MATCH_THRESHOLD: Balance = 25_000_000_000_000_000_000_000_000; // 25 NEAR
+pub const DEFAULT_QF_CALCULATION_BATCH_LIMIT: u64 = 100; // number of donor pairs processed per chef_calculate_qf_payouts call if no limit is provided
+pub const DEFAULT_PAYOUTS_BATCH_LIMIT: u64 = 50; // number of payouts processed per admin_process_payouts call if no limit is provided
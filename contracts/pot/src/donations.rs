@@ -10,6 +10,8 @@ pub type DonationId = u64;
 pub struct Donation {
     /// ID of the donor               
     pub donor_id: AccountId,
+    /// ID of the account that sent the funds (differs from donor_id when a trusted intermediary donates on behalf of the donor)
+    pub sent_by: AccountId,
     /// Amount donated         
     pub total_amount: u128,
     /// Amount after all fees/expenses (incl. storage)
@@ -30,6 +32,23 @@ pub struct Donation {
     pub chef_id: Option<AccountId>,
     /// Chef fee
     pub chef_fee: Option<u128>,
+    /// Amount refunded to the donor if the Pot was cancelled (matching pool donations), or if the project was disqualified before its escrowed donations were released (escrowed public donations)
+    pub refund_amount: Option<u128>,
+    /// Timestamp when the refund was made. None if not refunded.
+    pub refunded_at: Option<TimestampMs>,
+    /// Matching sub-pool the donation was earmarked for, if any (matching pool donations only)
+    pub sub_pool_id: Option<SubPoolId>,
+    /// Currency the donation was made in, if not the Pot's base currency (amounts & fees are denominated in this currency). None for base currency donations.
+    pub currency: Option<DonationCurrency>,
+}
+
+impl Donation {
+    /// Converts an amount denominated in the donation's currency into the Pot's base currency
+    pub fn normalize(&self, amount: u128) -> u128 {
+        self.currency
+            .as_ref()
+            .map_or(amount, |currency| currency.normalize(amount))
+    }
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -53,6 +72,8 @@ pub struct DonationExternal {
     pub id: DonationId,
     /// ID of the donor               
     pub donor_id: AccountId,
+    /// ID of the account that sent the funds (differs from donor_id when a trusted intermediary donates on behalf of the donor)
+    pub sent_by: AccountId,
     /// Amount donated         
     pub total_amount: U128,
     /// Amount after all fees/expenses (incl. storage)
@@ -75,10 +96,96 @@ pub struct DonationExternal {
     pub chef_id: Option<AccountId>,
     /// Chef fee
     pub chef_fee: Option<U128>,
+    /// Amount refunded to the donor if the Pot was cancelled (matching pool donations), or if the project was disqualified before its escrowed donations were released (escrowed public donations)
+    pub refund_amount: Option<U128>,
+    /// Timestamp when the refund was made. None if not refunded.
+    pub refunded_at: Option<TimestampMs>,
+    /// Matching sub-pool the donation was earmarked for, if any (matching pool donations only)
+    pub sub_pool_id: Option<SubPoolId>,
+    /// Currency the donation was made in, if not the Pot's base currency (amounts & fees are denominated in this currency). None for base currency donations.
+    pub currency: Option<DonationCurrency>,
+    /// Total amount, normalized into the Pot's base currency
+    pub normalized_total_amount: U128,
+    /// Net amount, normalized into the Pot's base currency (as used in stats & QF calculations)
+    pub normalized_net_amount: U128,
 }
 
 pub const DONATION_ID_DELIMETER: &str = ":";
 
+/// Expected `msg` (JSON-encoded) for `ft_on_transfer`; mirrors `donate` args
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FtReceiverMsg {
+    pub project_id: Option<ProjectId>,
+    pub message: Option<String>,
+    pub referrer_id: Option<AccountId>,
+    pub matching_pool: Option<bool>,
+    pub bypass_protocol_fee: Option<bool>,
+    pub custom_chef_fee_basis_points: Option<u32>,
+    pub on_behalf_of: Option<AccountId>,
+    pub earmark: Option<MatchingPoolEarmark>,
+}
+
+/// Public round donation to a single project, as part of a `donate_batch` call
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BatchDonationItem {
+    pub project_id: ProjectId,
+    /// Amount donated to this project (before fees)
+    pub amount: U128,
+    pub message: Option<String>,
+}
+
+/// Arguments of a single donation, passed along the donation flow (currency normalization, sybil checks, protocol fee) until the donation is processed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DonationArgs {
+    pub donor_id: AccountId,
+    /// Account that sent the funds (differs from donor_id when a trusted intermediary donates on behalf of the donor)
+    pub sent_by: AccountId,
+    /// Amount donated (before fees), denominated in `currency` if provided, otherwise in the Pot's base currency
+    pub deposit: Balance,
+    pub project_id: Option<ProjectId>,
+    pub message: Option<String>,
+    pub referrer_id: Option<AccountId>,
+    pub matching_pool: bool,
+    pub earmark: Option<MatchingPoolEarmark>,
+    pub bypass_protocol_fee: Option<bool>,
+    pub custom_chef_fee_basis_points: Option<u32>,
+    /// Set once the donation has been normalized via the price oracle provider (additional currency donations only)
+    pub currency: Option<DonationCurrency>,
+}
+
+/// Arguments of a `donate_batch` call, passed along the batch donation flow until the batch is processed
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BatchDonationArgs {
+    pub donor_id: AccountId,
+    pub sent_by: AccountId,
+    /// Total attached deposit (may exceed the sum of the batch's donations, in which case the difference is refunded)
+    pub deposit: Balance,
+    pub donations: Vec<BatchDonationItem>,
+    pub referrer_id: Option<AccountId>,
+    pub bypass_protocol_fee: Option<bool>,
+    pub custom_chef_fee_basis_points: Option<u32>,
+}
+
+/// Limits on public round donations (amounts are gross, i.e. before fees). Limits that are `None` are not enforced.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DonationLimits {
+    /// Minimum amount of a single public donation
+    pub min_amount: Option<U128>,
+    /// Maximum amount of a single public donation
+    pub max_amount: Option<U128>,
+    /// Maximum total amount a single donor can donate to a given project
+    pub max_per_donor_per_project: Option<U128>,
+    /// Maximum total amount a single donor can donate across all projects in the round
+    pub max_per_donor: Option<U128>,
+    /// Maximum total amount of public donations a single project can receive
+    pub max_per_project: Option<U128>,
+}
+
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct ProtocolConfigProviderResult {
@@ -86,6 +193,35 @@ pub struct ProtocolConfigProviderResult {
     pub account_id: AccountId,
 }
 
+/// Price returned by the price oracle provider: one unit (smallest denomination) of the donated currency is worth `multiplier / 10^decimals` units of the Pot's base currency
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PriceOracleProviderResult {
+    pub multiplier: U128,
+    pub decimals: u8,
+}
+
+/// Currency of a donation made in one of the Pot's additional accepted currencies, along with the price used to normalize it
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DonationCurrency {
+    /// "near" or NEP-141 contract ID
+    pub currency: AccountId,
+    /// Price returned by the price oracle provider when the donation was made
+    pub price: PriceOracleProviderResult,
+}
+
+impl DonationCurrency {
+    /// Converts an amount in this currency into the Pot's base currency (rounded down)
+    pub fn normalize(&self, amount: u128) -> u128 {
+        mul_div(
+            amount,
+            self.price.multiplier.0,
+            10u128.pow(self.price.decimals as u32),
+        )
+    }
+}
+
 #[near_bindgen]
 impl Contract {
     // GETTERS
@@ -237,11 +373,315 @@ impl Contract {
         matching_pool: Option<bool>,
         bypass_protocol_fee: Option<bool>,
         custom_chef_fee_basis_points: Option<u32>,
-    ) -> PromiseOrValue<DonationExternal> {
+        on_behalf_of: Option<AccountId>,
+        earmark: Option<MatchingPoolEarmark>,
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        self.assert_not_paused(PausableFeature::Donations);
+        // attribute donation to caller, unless a trusted intermediary is donating on behalf of another account
+        let sent_by = env::predecessor_account_id();
+        let donor_id = self.resolve_donor_id(&sent_by, on_behalf_of);
+        let is_matching_pool = matching_pool.unwrap_or(false);
+        let currency = AccountId::new_unchecked(NEAR_BASE_CURRENCY.to_string());
+        self.assert_accepted_currency(&currency, is_matching_pool);
+        let earmark = earmark.map(MatchingPoolEarmark::normalize);
+        self.assert_valid_donation(&donor_id, &sent_by, &project_id, is_matching_pool, &earmark);
+        let deposit = env::attached_deposit();
+        match self.handle_donation_currency(
+            DonationArgs {
+                donor_id,
+                sent_by: sent_by.clone(),
+                deposit,
+                project_id,
+                message,
+                referrer_id,
+                matching_pool: is_matching_pool,
+                earmark,
+                bypass_protocol_fee,
+                custom_chef_fee_basis_points,
+                currency: None,
+            },
+            currency,
+        ) {
+            PromiseOrValue::Promise(promise) => PromiseOrValue::Promise(
+                promise.then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(XCC_GAS)
+                        .donate_callback(sent_by, U128(deposit)),
+                ),
+            ),
+            PromiseOrValue::Value(donation) => {
+                if donation.is_none() {
+                    Promise::new(sent_by).transfer(deposit);
+                }
+                PromiseOrValue::Value(donation)
+            }
+        }
+    }
+
+    /// Returns attached deposit to sender if donation was rejected (e.g. by sybil checks) or failed; otherwise passes the donation through
+    #[private]
+    pub fn donate_callback(
+        &mut self,
+        sent_by: AccountId,
+        deposit: U128,
+        #[callback_result] call_result: Result<Option<DonationExternal>, PromiseError>,
+    ) -> Option<DonationExternal> {
+        match call_result {
+            Ok(Some(donation)) => Some(donation),
+            _ => {
+                log!(format!(
+                    "Donation was not processed; returning {} to {}",
+                    deposit.0, sent_by
+                ));
+                Promise::new(sent_by).transfer(deposit.0);
+                None
+            }
+        }
+    }
+
+    /// Donates to multiple projects in the public round with a single attached deposit. Sybil checks & protocol config lookup are only performed once for the whole batch.
+    /// Any deposit not allocated to the batch's donations is refunded. All donations are validated before any cross-contract calls are made; if the batch is rejected after that (e.g. by sybil checks), the whole deposit is returned & `None` is returned.
+    #[payable]
+    pub fn donate_batch(
+        &mut self,
+        donations: Vec<BatchDonationItem>,
+        referrer_id: Option<AccountId>,
+        bypass_protocol_fee: Option<bool>,
+        custom_chef_fee_basis_points: Option<u32>,
+        on_behalf_of: Option<AccountId>,
+    ) -> PromiseOrValue<Option<Vec<DonationExternal>>> {
+        self.assert_not_paused(PausableFeature::Donations);
+        assert!(
+            self.is_base_currency_near(),
+            "Batch donations are only supported for Pots with a base currency of {}",
+            NEAR_BASE_CURRENCY
+        );
+        let sent_by = env::predecessor_account_id();
+        let donor_id = self.resolve_donor_id(&sent_by, on_behalf_of);
+        let total_amount = self.assert_valid_batch_donation(&donor_id, &sent_by, &donations);
+        let deposit = env::attached_deposit();
+        assert!(
+            deposit >= total_amount,
+            "Attached deposit {} is less than total batch donations amount {}",
+            deposit,
+            total_amount
+        );
+        // check donation limits before making any cross-contract calls (re-checked when batch is processed)
+        if let Some(error) = self.get_batch_donation_limits_error(&donor_id, &donations) {
+            env::panic_str(&error);
+        }
+        let batch_args = BatchDonationArgs {
+            donor_id,
+            sent_by: sent_by.clone(),
+            deposit,
+            donations,
+            referrer_id,
+            bypass_protocol_fee,
+            custom_chef_fee_basis_points,
+        };
+        let result = if let Some((checks_promise, provider_weights)) =
+            self.query_custom_sybil_checks(&batch_args.donor_id)
+        {
+            PromiseOrValue::Promise(
+                checks_promise.then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(XCC_GAS)
+                        .batch_custom_sybil_checks_callback(batch_args, provider_weights),
+                ),
+            )
+        } else if let Some(sybil_promise) = self.query_sybil_wrapper_provider(&batch_args.donor_id)
+        {
+            PromiseOrValue::Promise(
+                sybil_promise.then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(XCC_GAS)
+                        .batch_sybil_callback(batch_args),
+                ),
+            )
+        } else {
+            // no sybil checks, so move on to protocol fee handler
+            self.handle_batch_protocol_fee(batch_args)
+        };
+        match result {
+            PromiseOrValue::Promise(promise) => PromiseOrValue::Promise(
+                promise.then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(XCC_GAS)
+                        .donate_batch_callback(sent_by, U128(deposit)),
+                ),
+            ),
+            PromiseOrValue::Value(donations) => {
+                if donations.is_none() {
+                    Promise::new(sent_by).transfer(deposit);
+                }
+                PromiseOrValue::Value(donations)
+            }
+        }
+    }
+
+    /// Returns the whole attached deposit to sender if the batch was rejected (e.g. by sybil checks) or failed; otherwise passes the donations through
+    #[private]
+    pub fn donate_batch_callback(
+        &mut self,
+        sent_by: AccountId,
+        deposit: U128,
+        #[callback_result] call_result: Result<Option<Vec<DonationExternal>>, PromiseError>,
+    ) -> Option<Vec<DonationExternal>> {
+        match call_result {
+            Ok(Some(donations)) => Some(donations),
+            _ => {
+                log!(format!(
+                    "Batch donation was not processed; returning {} to {}",
+                    deposit.0, sent_by
+                ));
+                Promise::new(sent_by).transfer(deposit.0);
+                None
+            }
+        }
+    }
+
+    /// FT equivalent of donate, for Pots whose base currency (or one of whose accepted currencies) is a NEP-141 fungible token
+    pub fn ft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        amount: U128,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        let ft_id = env::predecessor_account_id();
+        self.assert_not_paused(PausableFeature::Donations);
+        let msg_json: FtReceiverMsg = near_sdk::serde_json::from_str(&msg)
+            .expect("Invalid msg string. Must implement FtReceiverMsg.");
+        let donor_id = self.resolve_donor_id(&sender_id, msg_json.on_behalf_of);
+        let is_matching_pool = msg_json.matching_pool.unwrap_or(false);
+        self.assert_accepted_currency(&ft_id, is_matching_pool);
+        let earmark = msg_json.earmark.map(MatchingPoolEarmark::normalize);
+        self.assert_valid_donation(
+            &donor_id,
+            &sender_id,
+            &msg_json.project_id,
+            is_matching_pool,
+            &earmark,
+        );
+        match self.handle_donation_currency(
+            DonationArgs {
+                donor_id,
+                sent_by: sender_id,
+                deposit: amount.0,
+                project_id: msg_json.project_id,
+                message: msg_json.message,
+                referrer_id: msg_json.referrer_id,
+                matching_pool: is_matching_pool,
+                earmark,
+                bypass_protocol_fee: msg_json.bypass_protocol_fee,
+                custom_chef_fee_basis_points: msg_json.custom_chef_fee_basis_points,
+                currency: None,
+            },
+            ft_id.clone(),
+        ) {
+            PromiseOrValue::Promise(promise) => PromiseOrValue::Promise(
+                promise.then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(XCC_GAS)
+                        .ft_on_transfer_callback(amount, ft_id),
+                ),
+            ),
+            // donation processed synchronously; all tokens used, as per NEP-141 standard
+            PromiseOrValue::Value(Some(_donation)) => PromiseOrValue::Value(U128(0)),
+            PromiseOrValue::Value(None) => PromiseOrValue::Value(amount),
+        }
+    }
+
+    /// Returns # unused tokens to FT contract (all tokens if donation was rejected or failed, none if it succeeded)
+    #[private]
+    pub fn ft_on_transfer_callback(
+        &mut self,
+        amount: U128,
+        ft_id: AccountId,
+        #[callback_result] call_result: Result<Option<DonationExternal>, PromiseError>,
+    ) -> U128 {
+        if !matches!(call_result, Ok(Some(_))) {
+            log!(format!(
+                "Donation failed; returning {} {} to donor",
+                amount.0, ft_id
+            ));
+            amount
+        } else {
+            U128(0)
+        }
+    }
+
+    /// Asserts that the Pot accepts donations in `currency` ("near" or NEP-141 contract ID). Additional currencies are only accepted for public round donations.
+    pub(crate) fn assert_accepted_currency(&self, currency: &AccountId, is_matching_pool: bool) {
+        if currency == &self.base_currency {
+            return;
+        }
+        assert!(
+            self.accepted_currencies.contains(currency),
+            "This Pot does not accept donations in {}",
+            currency
+        );
+        assert!(
+            !is_matching_pool,
+            "Matching pool donations can only be made in {}",
+            self.base_currency
+        );
+    }
+
+    /// Moves on to donation checks directly for base currency donations; otherwise queries the price oracle first, so that the donation can be normalized into the base currency
+    pub(crate) fn handle_donation_currency(
+        &mut self,
+        args: DonationArgs,
+        currency: AccountId,
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        if currency == self.base_currency {
+            return self.assert_caller_can_donate(args);
+        }
+        // only public round donations can be made in additional currencies (see assert_accepted_currency)
+        PromiseOrValue::Promise(
+            self.query_price_oracle_provider(&currency).then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(XCC_GAS)
+                    .price_oracle_callback(args, currency),
+            ),
+        )
+    }
+
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn price_oracle_callback(
+        &mut self,
+        args: DonationArgs,
+        currency: AccountId,
+        #[callback_result] call_result: Result<PriceOracleProviderResult, PromiseError>,
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        let price = call_result
+            .ok()
+            .filter(|price| price.multiplier.0 > 0 && price.decimals <= MAX_PRICE_DECIMALS);
+        let Some(price) = price else {
+            // don't panic, so that the donation is returned to donor by donate_callback (NEAR) or the FT contract (via ft_on_transfer_callback)
+            log!(format!(
+                "Error querying price of {} in {}; returning donation {} to donor {}",
+                currency, self.base_currency, args.deposit, args.sent_by
+            ));
+            return PromiseOrValue::Value(None);
+        };
+        self.assert_caller_can_donate(DonationArgs {
+            currency: Some(DonationCurrency { currency, price }),
+            ..args
+        })
+    }
+
+    pub(crate) fn assert_valid_donation(
+        &self,
+        donor_id: &AccountId,
+        sent_by: &AccountId,
+        project_id: &Option<ProjectId>,
+        is_matching_pool: bool,
+        earmark: &Option<MatchingPoolEarmark>,
+    ) {
+        self.assert_not_cancelled();
         if let Some(project_id) = project_id.clone() {
             self.assert_approved_application(&project_id);
         };
-        let is_matching_pool = matching_pool.unwrap_or(false);
         if is_matching_pool {
             // matching pool validations
             // matching pool donations can be received at any point until public round closes
@@ -252,6 +692,9 @@ impl Contract {
                     "project_id argument must not be provided for matching pool donations",
                 );
             }
+            if let Some(earmark) = earmark {
+                self.assert_matching_sub_pool_exists(earmark);
+            }
         } else {
             // public round validations
             // public round donations can only be received while public round is open/active
@@ -262,87 +705,221 @@ impl Contract {
                     "project_id argument must be provided for public (non-matching pool) donations",
                 );
             }
+            if earmark.is_some() {
+                env::panic_str("earmark argument can only be provided for matching pool donations");
+            }
         }
         // don't allow a project to donate to itself
         if let Some(project_id) = project_id.clone() {
-            if project_id == env::predecessor_account_id() || project_id == env::signer_account_id()
+            if &project_id == donor_id
+                || &project_id == sent_by
+                || project_id == env::signer_account_id()
             {
                 env::panic_str("Projects cannot donate to themselves");
             }
         }
         // TODO: may want to prohibit additions to matching pool once public round has closed?
-        let deposit = env::attached_deposit();
-        self.assert_caller_can_donate(
-            deposit,
-            project_id,
-            message,
-            referrer_id,
-            is_matching_pool,
-            bypass_protocol_fee,
-            custom_chef_fee_basis_points,
+    }
+
+    /// Validates each donation in a batch, returning the total amount of the batch
+    pub(crate) fn assert_valid_batch_donation(
+        &self,
+        donor_id: &AccountId,
+        sent_by: &AccountId,
+        donations: &[BatchDonationItem],
+    ) -> Balance {
+        assert!(
+            !donations.is_empty(),
+            "Batch must contain at least one donation"
+        );
+        assert!(
+            donations.len() <= MAX_DONATIONS_PER_BATCH,
+            "Batch cannot contain more than {} donations",
+            MAX_DONATIONS_PER_BATCH
+        );
+        let mut project_ids: HashSet<&ProjectId> = HashSet::new();
+        let mut total_amount: Balance = 0;
+        for donation in donations.iter() {
+            assert!(
+                project_ids.insert(&donation.project_id),
+                "Batch contains more than one donation to project {}",
+                donation.project_id
+            );
+            assert!(
+                donation.amount.0 > 0,
+                "Donation amount for project {} must be greater than 0",
+                donation.project_id
+            );
+            self.assert_valid_donation(
+                donor_id,
+                sent_by,
+                &Some(donation.project_id.clone()),
+                false,
+                &None,
+            );
+            total_amount = total_amount
+                .checked_add(donation.amount.0)
+                .expect("Overflow occurred when calculating total batch donations amount");
+        }
+        total_amount
+    }
+
+    /// Queries all custom sybil providers for `account_id` in parallel, returning the joint promise & provider weights (in the same order as the promise results)
+    pub(crate) fn query_custom_sybil_checks(
+        &self,
+        account_id: &AccountId,
+    ) -> Option<(Promise, Vec<SybilProviderWeight>)> {
+        let custom_sybil_checks = self
+            .custom_sybil_checks
+            .get()
+            .filter(|custom_sybil_checks| !custom_sybil_checks.is_empty())?;
+        let args = json!({ "account_id": account_id }).to_string().into_bytes();
+        let mut provider_weights: Vec<SybilProviderWeight> = vec![];
+        let mut checks_promise: Option<Promise> = None;
+        for (provider_id, weight) in custom_sybil_checks.iter() {
+            let (contract_id, method_name) = provider_id.decompose();
+            let check_promise = Promise::new(AccountId::new_unchecked(contract_id)).function_call(
+                method_name,
+                args.clone(),
+                0,
+                CUSTOM_SYBIL_CHECK_GAS,
+            );
+            checks_promise = Some(match checks_promise {
+                Some(checks_promise) => checks_promise.and(check_promise),
+                None => check_promise,
+            });
+            provider_weights.push(*weight);
+        }
+        checks_promise.map(|checks_promise| (checks_promise, provider_weights))
+    }
+
+    /// Sums weights of custom sybil providers that returned true (in the same order as `provider_weights`); returns (score, threshold)
+    pub(crate) fn get_custom_sybil_checks_score(
+        &self,
+        provider_weights: &[SybilProviderWeight],
+    ) -> (u32, u32) {
+        let mut score: u32 = 0;
+        for (index, weight) in provider_weights.iter().enumerate() {
+            match env::promise_result(index as u64) {
+                PromiseResult::Successful(value) => {
+                    if near_sdk::serde_json::from_slice::<bool>(&value).unwrap_or(false) {
+                        score = score.saturating_add(*weight);
+                    }
+                }
+                _ => log!(format!(
+                    "Error querying custom sybil check {}; check will not be counted",
+                    index
+                )),
+            }
+        }
+        // if no threshold has been set, all custom sybil checks must pass
+        let threshold = self.custom_min_threshold_score.get().unwrap_or(
+            provider_weights
+                .iter()
+                .fold(0u32, |total, weight| total.saturating_add(*weight)),
+        );
+        (score, threshold)
+    }
+
+    pub(crate) fn query_sybil_wrapper_provider(&self, account_id: &AccountId) -> Option<Promise> {
+        let (contract_id, method_name) = self.sybil_wrapper_provider.get()?.decompose();
+        let args = json!({ "account_id": account_id }).to_string().into_bytes();
+        Some(
+            Promise::new(AccountId::new_unchecked(contract_id)).function_call(
+                method_name,
+                args,
+                0,
+                Gas(TGAS * 50),
+            ),
+        )
+    }
+
+    pub(crate) fn query_price_oracle_provider(&self, currency: &AccountId) -> Promise {
+        let (contract_id, method_name) = self
+            .price_oracle_provider
+            .get()
+            .expect("No price oracle provider set")
+            .decompose();
+        let args = json!({ "currency": currency, "base_currency": self.base_currency })
+            .to_string()
+            .into_bytes();
+        Promise::new(AccountId::new_unchecked(contract_id)).function_call(
+            method_name,
+            args,
+            0,
+            XCC_GAS,
+        )
+    }
+
+    pub(crate) fn query_protocol_config_provider(&self) -> Option<Promise> {
+        let (contract_id, method_name) = self.protocol_config_provider.get()?.decompose();
+        let args = json!({}).to_string().into_bytes();
+        Some(
+            Promise::new(AccountId::new_unchecked(contract_id)).function_call(
+                method_name,
+                args,
+                0,
+                XCC_GAS,
+            ),
         )
     }
 
     pub(crate) fn assert_caller_can_donate(
         &mut self,
-        deposit: Balance,
-        project_id: Option<ProjectId>,
-        message: Option<String>,
-        referrer_id: Option<AccountId>,
-        matching_pool: bool,
-        bypass_protocol_fee: Option<bool>,
-        custom_chef_fee_basis_points: Option<u32>,
-    ) -> PromiseOrValue<DonationExternal> {
-        let caller_id = env::predecessor_account_id();
-        if matching_pool {
+        args: DonationArgs,
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        if args.matching_pool {
             assert!(
-                deposit >= self.min_matching_pool_donation_amount,
-                "Matching pool donations must be at least {} yoctoNEAR",
-                self.min_matching_pool_donation_amount
+                args.deposit >= self.min_matching_pool_donation_amount,
+                "Matching pool donations must be at least {} ({})",
+                self.min_matching_pool_donation_amount,
+                self.base_currency
             );
-            // matching pool donations not subject to sybil checks, so move on to protocol fee handler
-            self.handle_protocol_fee(
-                deposit,
-                project_id.clone(),
-                message.clone(),
-                referrer_id.clone(),
-                matching_pool,
-                bypass_protocol_fee,
-                custom_chef_fee_basis_points,
-            )
+            // matching pool donations not subject to sybil checks or donation limits, so move on to protocol fee handler
+            self.handle_protocol_fee(args)
         } else {
-            if let Some(sybil_wrapper_provider) = self.sybil_wrapper_provider.get() {
-                let (contract_id, method_name) = sybil_wrapper_provider.decompose();
-                let args = json!({ "account_id": caller_id.clone() })
-                    .to_string()
-                    .into_bytes();
-                PromiseOrValue::Promise(Promise::new(AccountId::new_unchecked(contract_id.clone()))
-                    .function_call(method_name, args, 0, Gas(TGAS * 50))
-                    .then(
+            // check donation limits before making any cross-contract calls (re-checked when donation is processed)
+            let normalized_deposit = args
+                .currency
+                .as_ref()
+                .map_or(args.deposit, |currency| currency.normalize(args.deposit));
+            if let Some(error) = self.get_donation_limits_error(
+                &args.donor_id,
+                args.project_id.as_ref().expect("no project ID"),
+                normalized_deposit,
+                0,
+            ) {
+                if args.currency.is_some() {
+                    // already in price_oracle_callback; don't panic, so that the donation is returned to donor
+                    log!(format!(
+                        "{}; returning donation {} to donor {}",
+                        error, args.deposit, args.sent_by
+                    ));
+                    return PromiseOrValue::Value(None);
+                }
+                env::panic_str(&error);
+            }
+            if let Some((checks_promise, provider_weights)) =
+                self.query_custom_sybil_checks(&args.donor_id)
+            {
+                PromiseOrValue::Promise(
+                    checks_promise.then(
+                        Self::ext(env::current_account_id())
+                            .with_static_gas(XCC_GAS)
+                            .custom_sybil_checks_callback(args, provider_weights),
+                    ),
+                )
+            } else if let Some(sybil_promise) = self.query_sybil_wrapper_provider(&args.donor_id) {
+                PromiseOrValue::Promise(
+                    sybil_promise.then(
                         Self::ext(env::current_account_id())
                             .with_static_gas(XCC_GAS)
-                            .sybil_callback(
-                                caller_id,
-                                deposit,
-                                project_id.clone(),
-                                message.clone(),
-                                referrer_id.clone(),
-                                matching_pool,
-                                bypass_protocol_fee,
-                                custom_chef_fee_basis_points,
-                            ),
-                    ))
+                            .sybil_callback(args),
+                    ),
+                )
             } else {
                 // no sybil wrapper provider, so move on to protocol fee handler
-                self.handle_protocol_fee(
-                    deposit,
-                    project_id.clone(),
-                    message.clone(),
-                    referrer_id.clone(),
-                    matching_pool,
-                    bypass_protocol_fee,
-                    custom_chef_fee_basis_points
-                )
+                self.handle_protocol_fee(args)
             }
         }
     }
@@ -350,101 +927,63 @@ impl Contract {
     #[private] // Public - but only callable by env::current_account_id()
     pub fn sybil_callback(
         &mut self,
-        caller_id: AccountId,
-        deposit: Balance,
-        project_id: Option<ProjectId>,
-        message: Option<String>,
-        referrer_id: Option<AccountId>,
-        matching_pool: bool,
-        bypass_protocol_fee: Option<bool>,
-        custom_chef_fee_basis_points: Option<u32>,
+        args: DonationArgs,
         #[callback_result] call_result: Result<bool, PromiseError>,
-    ) -> PromiseOrValue<DonationExternal> {
-        if call_result.is_err() {
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        // rejected donations are returned to donor by donate_callback (NEAR) or the FT contract (via ft_on_transfer_callback)
+        let error = match call_result {
+            Err(_) => Some("There was an error querying sybil check"),
+            Ok(false) => Some("Sybil provider wrapper check returned false"),
+            Ok(true) => None,
+        };
+        if let Some(error) = error {
             log!(format!(
-                "Error verifying sybil check; returning donation {} to donor {}",
-                deposit, caller_id
+                "{}; returning donation {} to donor {}",
+                error, args.deposit, args.sent_by
             ));
-            Promise::new(caller_id).transfer(deposit);
-            env::panic_str(
-                "There was an error querying sybil check. Donation has been returned to donor.",
-            );
+            return PromiseOrValue::Value(None);
         }
-        let is_human: bool = call_result.unwrap();
-        if !is_human {
+        self.handle_protocol_fee(args)
+    }
+
+    /// Sums weights of custom sybil providers that returned true (in the same order as `provider_weights`) and compares against `custom_min_threshold_score`
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn custom_sybil_checks_callback(
+        &mut self,
+        args: DonationArgs,
+        provider_weights: Vec<SybilProviderWeight>,
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        let (score, threshold) = self.get_custom_sybil_checks_score(&provider_weights);
+        if score < threshold {
             log!(format!(
-                "Sybil provider wrapper check returned false; returning donation {} to donor {}",
-                deposit, caller_id
+                "Custom sybil checks score {} is below threshold {}; returning donation {} to donor {}",
+                score, threshold, args.deposit, args.sent_by
             ));
-            Promise::new(caller_id).transfer(deposit);
-            env::panic_str(
-                "Sybil provider wrapper check returned false. Donation has been returned to donor.",
-            );
-        } else {
-            self.handle_protocol_fee(
-                deposit,
-                project_id,
-                message,
-                referrer_id,
-                matching_pool,
-                bypass_protocol_fee,
-                custom_chef_fee_basis_points,
-            )
+            // returned to donor by donate_callback (NEAR) or the FT contract (via ft_on_transfer_callback)
+            return PromiseOrValue::Value(None);
         }
+        self.handle_protocol_fee(args)
     }
 
     #[private]
     pub fn handle_protocol_fee(
         &mut self,
-        deposit: Balance,
-        project_id: Option<ProjectId>,
-        message: Option<String>,
-        referrer_id: Option<AccountId>,
-        matching_pool: bool,
-        bypass_protocol_fee: Option<bool>,
-        custom_chef_fee_basis_points: Option<u32>,
-    ) -> PromiseOrValue<DonationExternal> {
-        if bypass_protocol_fee.unwrap_or(false) {
+        args: DonationArgs,
+    ) -> PromiseOrValue<Option<DonationExternal>> {
+        if args.bypass_protocol_fee.unwrap_or(false) {
             // bypass protocol fee
-            PromiseOrValue::Value(self.process_donation(
-                    deposit,
-                    0,
-                    None,
-                    project_id.clone(),
-                    message.clone(),
-                    referrer_id.clone(),
-                    matching_pool,
-                    custom_chef_fee_basis_points,
-                ))
-        } else if let Some(protocol_config_provider) = self.protocol_config_provider.get() {
-            let (contract_id, method_name) = protocol_config_provider.decompose();
-            let args = json!({}).to_string().into_bytes();
-            PromiseOrValue::Promise(Promise::new(AccountId::new_unchecked(contract_id.clone()))
-                .function_call(method_name.clone(), args, 0, XCC_GAS)
-                .then(
+            PromiseOrValue::Value(self.process_donation(args, 0, None))
+        } else if let Some(protocol_config_promise) = self.query_protocol_config_provider() {
+            PromiseOrValue::Promise(
+                protocol_config_promise.then(
                     Self::ext(env::current_account_id())
                         .with_static_gas(XCC_GAS)
-                        .handle_protocol_fee_callback(
-                            deposit,
-                            project_id,
-                            message,
-                            referrer_id,
-                            matching_pool,
-                            custom_chef_fee_basis_points,
-                        ),
-                ))
+                        .handle_protocol_fee_callback(args),
+                ),
+            )
         } else {
             // bypass protocol fee
-            PromiseOrValue::Value(self.process_donation(
-                deposit,
-                0,
-                None,
-                project_id.clone(),
-                message.clone(),
-                referrer_id.clone(),
-                matching_pool,
-                custom_chef_fee_basis_points,
-            ))
+            PromiseOrValue::Value(self.process_donation(args, 0, None))
         }
     }
 
@@ -452,81 +991,105 @@ impl Contract {
     #[private]
     pub fn handle_protocol_fee_callback(
         &mut self,
-        deposit: Balance,
-        project_id: Option<ProjectId>,
-        message: Option<String>,
-        referrer_id: Option<AccountId>,
-        matching_pool: bool,
-        custom_chef_fee_basis_points: Option<u32>,
+        args: DonationArgs,
         #[callback_result] call_result: Result<ProtocolConfigProviderResult, PromiseError>,
-    ) -> DonationExternal {
+    ) -> Option<DonationExternal> {
         if call_result.is_err() {
             log!(format!(
                 "Error getting protocol fee; continuing with donation",
             ));
-            self.process_donation(
-                deposit,
-                0,
-                None,
-                project_id,
-                message,
-                referrer_id,
-                matching_pool,
-                custom_chef_fee_basis_points,
-            )
+            self.process_donation(args, 0, None)
         } else {
             let protocol_config_provider_result = call_result.unwrap();
             let protocol_fee_basis_points = std::cmp::min(protocol_config_provider_result.basis_points, MAX_PROTOCOL_FEE_BASIS_POINTS);
             let protocol_fee_recipient_account = protocol_config_provider_result.account_id;
             // calculate protocol fee (don't transfer yet)
-            let protocol_fee = self.calculate_fee(deposit, protocol_fee_basis_points, true);
-            self.process_donation(
-                deposit,
-                protocol_fee,
-                Some(protocol_fee_recipient_account),
-                project_id,
-                message,
-                referrer_id,
-                matching_pool,
-                custom_chef_fee_basis_points,
-            )
+            let protocol_fee = self.calculate_fee(args.deposit, protocol_fee_basis_points, true);
+            self.process_donation(args, protocol_fee, Some(protocol_fee_recipient_account))
         }
     }
 
     #[private]
     pub fn process_donation(
         &mut self,
-        deposit: Balance,
+        args: DonationArgs,
+        protocol_fee: u128,
+        protocol_fee_recipient_account: Option<AccountId>,
+    ) -> Option<DonationExternal> {
+        // re-check pause state & donation limits, as these may have changed since the initial checks
+        let error = if self.paused_features.contains(&PausableFeature::Donations) {
+            Some("Donations are currently paused".to_string())
+        } else if !args.matching_pool {
+            self.get_donation_limits_error(
+                &args.donor_id,
+                args.project_id.as_ref().expect("no project ID"),
+                args.currency
+                    .as_ref()
+                    .map_or(args.deposit, |currency| currency.normalize(args.deposit)),
+                0,
+            )
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            // don't panic, so that the donation is returned to donor by donate_callback (NEAR) or the FT contract (via ft_on_transfer_callback)
+            log!(format!(
+                "{}; returning donation {} to donor {}",
+                error, args.deposit, args.sent_by
+            ));
+            return None;
+        }
+        Some(self.record_donation(args, protocol_fee, protocol_fee_recipient_account))
+    }
+
+    /// Records a donation that has passed all checks, transferring fees & remainder
+    pub(crate) fn record_donation(
+        &mut self,
+        args: DonationArgs,
         protocol_fee: u128,
         protocol_fee_recipient_account: Option<AccountId>,
-        project_id: Option<ProjectId>,
-        message: Option<String>,
-        referrer_id: Option<AccountId>,
-        matching_pool: bool,
-        custom_chef_fee_basis_points: Option<u32>,
     ) -> DonationExternal {
+        let DonationArgs {
+            donor_id,
+            sent_by,
+            deposit,
+            project_id,
+            message,
+            referrer_id,
+            matching_pool,
+            earmark,
+            bypass_protocol_fee: _,
+            custom_chef_fee_basis_points,
+            currency,
+        } = args;
         let initial_storage_usage = env::storage_usage();
 
         // subtract protocol fee
-        let mut remainder = deposit.checked_sub(protocol_fee).expect(&format!(
-            "Overflow occurred when calculating remainder ({} - {})",
-            deposit, protocol_fee,
-        ));
+        let mut remainder = deposit.checked_sub(protocol_fee).unwrap_or_else(|| {
+            panic!(
+                "Overflow occurred when calculating remainder ({} - {})",
+                deposit, protocol_fee,
+            )
+        });
 
         // subtract chef fee, unless bypassed
         let mut chef_fee: Option<U128> = None;
         let mut chef_id: Option<AccountId> = None;
         if let Some(chef) = self.chef.get() {
-            let chef_fee_basis_points = std::cmp::min(custom_chef_fee_basis_points.unwrap_or(self.chef_fee_basis_points), self.chef_fee_basis_points); // can't provide a chef fee basis points greater than the contract's
+            let chef_fee_basis_points = std::cmp::min(
+                custom_chef_fee_basis_points.unwrap_or(self.chef_fee_basis_points),
+                self.chef_fee_basis_points,
+            ); // can't provide a chef fee basis points greater than the contract's
             if chef_fee_basis_points > 0 {
-                let chef_fee_amount =
-                    self.calculate_fee(remainder, chef_fee_basis_points, false);
+                let chef_fee_amount = self.calculate_fee(remainder, chef_fee_basis_points, false);
                 chef_fee = Some(U128::from(chef_fee_amount));
                 chef_id = Some(chef);
-                remainder = remainder.checked_sub(chef_fee_amount).expect(&format!(
-                    "Overflow occurred when calculating remainder ({} - {})",
-                    remainder, chef_fee_amount,
-                ));
+                remainder = remainder.checked_sub(chef_fee_amount).unwrap_or_else(|| {
+                    panic!(
+                        "Overflow occurred when calculating remainder ({} - {})",
+                        remainder, chef_fee_amount,
+                    )
+                });
             }
         }
 
@@ -535,16 +1098,27 @@ impl Contract {
         if let Some(_referrer_id) = referrer_id.clone() {
             let referrer_fee_amount = self.calculate_referrer_fee(remainder, matching_pool);
             referrer_fee = Some(U128::from(referrer_fee_amount));
-            remainder = remainder.checked_sub(referrer_fee_amount).expect(&format!(
-                "Overflow occurred when calculating remainder ({} - {})",
-                remainder, referrer_fee_amount,
-            ));
+            remainder = remainder
+                .checked_sub(referrer_fee_amount)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Overflow occurred when calculating remainder ({} - {})",
+                        remainder, referrer_fee_amount,
+                    )
+                });
         }
 
+        // earmarked matching pool donations are tracked in their own sub-pool
+        let sub_pool_id = earmark.map(|earmark| {
+            self.get_matching_sub_pool_id(&earmark)
+                .expect("Matching sub-pool does not exist")
+        });
+
         // insert mappings
         let donation_id = (self.donations_by_id.len() + 1) as DonationId;
         let donation = Donation {
-            donor_id: env::signer_account_id(),
+            donor_id: donor_id.clone(),
+            sent_by: sent_by.clone(),
             total_amount: deposit,
             net_amount: 0, // this will be updated in a moment after storage cost is subtracted
             message,
@@ -555,82 +1129,486 @@ impl Contract {
             referrer_fee: referrer_fee.map(|v| v.0),
             chef_id: chef_id.clone(),
             chef_fee: chef_fee.map(|v| v.0),
+            refund_amount: None,
+            refunded_at: None,
+            sub_pool_id,
+            currency,
         };
         self.insert_donation_record(&donation_id, &donation, matching_pool);
+        if self.escrow_public_donations && !matching_pool {
+            // ensure project's escrow entry exists before storage cost is calculated (its balance is updated once the net amount is known)
+            self.add_escrowed_donations(project_id.as_ref().expect("no project ID"), 0);
+        }
 
-        // assert that donation after fees > storage cost
-        let required_deposit = calculate_required_storage_deposit(initial_storage_usage);
-        require!(
-            remainder > required_deposit,
-            format!(
-                "Must attach {} yoctoNEAR to cover storage",
-                required_deposit
-            )
-        );
+        if self.is_near_donation(&donation.currency) {
+            // assert that donation after fees > storage cost
+            let required_deposit = calculate_required_storage_deposit(initial_storage_usage);
+            require!(
+                remainder > required_deposit,
+                format!(
+                    "Must attach {} yoctoNEAR to cover storage",
+                    required_deposit
+                )
+            );
 
-        // subtract storage cost
-        remainder = remainder.checked_sub(required_deposit).expect(&format!(
-            "Overflow occurred when calculating remainder ({} - {})",
-            remainder, required_deposit,
-        ));
+            // subtract storage cost
+            remainder = remainder.checked_sub(required_deposit).unwrap_or_else(|| {
+                panic!(
+                    "Overflow occurred when calculating remainder ({} - {})",
+                    remainder, required_deposit,
+                )
+            });
+        } else {
+            // storage for FT donations is covered by the sender's storage deposit
+            self.verify_and_update_storage_balance(&sent_by, initial_storage_usage);
+        }
 
         // update donation with net amount
+        let donation = Donation {
+            net_amount: remainder,
+            ..donation
+        };
         self.donations_by_id
-            .insert(&donation_id, &VersionedDonation::Current(Donation {
-                net_amount: remainder,
-                ..donation.clone()
-            }));
+            .insert(&donation_id, &VersionedDonation::Current(donation.clone()));
 
         // update totals
         if matching_pool {
             self.total_matching_pool_donations = 
                 self.total_matching_pool_donations
                     .checked_add(remainder)
-                    .expect(&format!(
+                    .unwrap_or_else(|| panic!(
                         "Overflow occurred when calculating self.total_matching_pool_donations ({} + {})",
                         self.total_matching_pool_donations, remainder,
                     ));
-            self.matching_pool_balance = 
-                self.matching_pool_balance
-                    .checked_add(remainder)
-                    .expect(&format!(
+            self.matching_pool_balance = self
+                .matching_pool_balance
+                .checked_add(remainder)
+                .unwrap_or_else(|| {
+                    panic!(
                         "Overflow occurred when calculating self.matching_pool_balance ({} + {})",
                         self.matching_pool_balance, remainder,
-                    ));
+                    )
+                });
+            if let Some(sub_pool_id) = sub_pool_id {
+                self.add_matching_sub_pool_donation(sub_pool_id, remainder);
+            }
+            self.reset_qf_allocation();
         } else {
-            self.total_public_donations = 
-                self.total_public_donations
-                    .checked_add(remainder)
-                    .expect(&format!(
+            // public round totals are denominated in the base currency
+            let normalized_remainder = donation.normalize(remainder);
+            self.total_public_donations = self
+                .total_public_donations
+                .checked_add(normalized_remainder)
+                .unwrap_or_else(|| {
+                    panic!(
                         "Overflow occurred when calculating self.total_public_donations ({} + {})",
-                        self.total_public_donations, remainder,
-                    ));
+                        self.total_public_donations, normalized_remainder,
+                    )
+                });
+            self.add_project_stats_net_amount(
+                project_id.as_ref().expect("no project ID"),
+                &donor_id,
+                normalized_remainder,
+            );
         }
 
+        // fees & remainder are transferred in the currency donated
+        let currency_id = donation
+            .currency
+            .as_ref()
+            .map_or(self.base_currency.clone(), |currency| {
+                currency.currency.clone()
+            });
+
         // transfer protocol fee
         if let Some(protocol_fee_recipient_account) = protocol_fee_recipient_account {
-            Promise::new(protocol_fee_recipient_account.clone()).transfer(protocol_fee);
+            self.transfer_currency(&currency_id, &protocol_fee_recipient_account, protocol_fee);
         }
 
         // transfer chef fee
         if let Some(chef_fee) = chef_fee {
             // it has already been established that chef is Some
-            Promise::new(chef_id.expect("no chef ID")).transfer(chef_fee.0);
+            self.transfer_currency(&currency_id, &chef_id.expect("no chef ID"), chef_fee.0);
         }
 
         // transfer referrer fee
         if let Some(referrer_fee) = referrer_fee {
             // it has already been established that referrer_id is Some
-            Promise::new(referrer_id.expect("no referrer ID")).transfer(referrer_fee.0);
+            self.transfer_currency(
+                &currency_id,
+                &referrer_id.expect("no referrer ID"),
+                referrer_fee.0,
+            );
         }
 
-        // transfer remainder to project
+        // transfer remainder to project, or hold it in escrow until payouts are processed
         if let Some(project_id) = project_id {
-            Promise::new(project_id.clone()).transfer(remainder);
+            if self.escrow_public_donations {
+                self.add_escrowed_donations(&project_id, remainder);
+            } else {
+                self.transfer_currency(&currency_id, &project_id, remainder);
+            }
         }
 
-        // return formatted donation
-        self.format_donation(&donation, donation_id)
+        // log & return formatted donation
+        let formatted_donation = self.format_donation(&donation, donation_id);
+        log_donation_event(&formatted_donation);
+        formatted_donation
+    }
+
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn batch_sybil_callback(
+        &mut self,
+        args: BatchDonationArgs,
+        #[callback_result] call_result: Result<bool, PromiseError>,
+    ) -> PromiseOrValue<Option<Vec<DonationExternal>>> {
+        let error = match call_result {
+            Err(_) => Some("There was an error querying sybil check"),
+            Ok(false) => Some("Sybil provider wrapper check returned false"),
+            Ok(true) => None,
+        };
+        if let Some(error) = error {
+            // returned to sender by donate_batch_callback
+            log!(format!(
+                "{}; returning batch donation {} to {}",
+                error, args.deposit, args.sent_by
+            ));
+            return PromiseOrValue::Value(None);
+        }
+        self.handle_batch_protocol_fee(args)
+    }
+
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn batch_custom_sybil_checks_callback(
+        &mut self,
+        args: BatchDonationArgs,
+        provider_weights: Vec<SybilProviderWeight>,
+    ) -> PromiseOrValue<Option<Vec<DonationExternal>>> {
+        let (score, threshold) = self.get_custom_sybil_checks_score(&provider_weights);
+        if score < threshold {
+            log!(format!(
+                "Custom sybil checks score {} is below threshold {}; returning batch donation {} to {}",
+                score, threshold, args.deposit, args.sent_by
+            ));
+            // returned to sender by donate_batch_callback
+            return PromiseOrValue::Value(None);
+        }
+        self.handle_batch_protocol_fee(args)
+    }
+
+    #[private]
+    pub fn handle_batch_protocol_fee(
+        &mut self,
+        args: BatchDonationArgs,
+    ) -> PromiseOrValue<Option<Vec<DonationExternal>>> {
+        let protocol_config_promise = if args.bypass_protocol_fee.unwrap_or(false) {
+            None
+        } else {
+            self.query_protocol_config_provider()
+        };
+        if let Some(protocol_config_promise) = protocol_config_promise {
+            PromiseOrValue::Promise(
+                protocol_config_promise.then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(XCC_GAS)
+                        .handle_batch_protocol_fee_callback(args),
+                ),
+            )
+        } else {
+            // bypass protocol fee
+            PromiseOrValue::Value(self.process_batch_donation(args, 0, None))
+        }
+    }
+
+    #[private]
+    pub fn handle_batch_protocol_fee_callback(
+        &mut self,
+        args: BatchDonationArgs,
+        #[callback_result] call_result: Result<ProtocolConfigProviderResult, PromiseError>,
+    ) -> Option<Vec<DonationExternal>> {
+        let (protocol_fee_basis_points, protocol_fee_recipient_account) = match call_result {
+            Ok(protocol_config_provider_result) => (
+                std::cmp::min(
+                    protocol_config_provider_result.basis_points,
+                    MAX_PROTOCOL_FEE_BASIS_POINTS,
+                ),
+                Some(protocol_config_provider_result.account_id),
+            ),
+            Err(_) => {
+                log!("Error getting protocol fee; continuing with batch donation");
+                (0, None)
+            }
+        };
+        self.process_batch_donation(
+            args,
+            protocol_fee_basis_points,
+            protocol_fee_recipient_account,
+        )
+    }
+
+    #[private]
+    pub fn process_batch_donation(
+        &mut self,
+        args: BatchDonationArgs,
+        protocol_fee_basis_points: u32,
+        protocol_fee_recipient_account: Option<AccountId>,
+    ) -> Option<Vec<DonationExternal>> {
+        let BatchDonationArgs {
+            donor_id,
+            sent_by,
+            deposit,
+            donations,
+            referrer_id,
+            bypass_protocol_fee,
+            custom_chef_fee_basis_points,
+        } = args;
+        // re-check pause state & donation limits for the whole batch before recording any donations, so that the batch fails as a unit
+        let error = if self.paused_features.contains(&PausableFeature::Donations) {
+            Some("Donations are currently paused".to_string())
+        } else {
+            self.get_batch_donation_limits_error(&donor_id, &donations)
+        };
+        if let Some(error) = error {
+            // don't panic, so that the whole deposit is returned to sender by donate_batch_callback
+            log!(format!(
+                "{}; returning batch donation {} to {}",
+                error, deposit, sent_by
+            ));
+            return None;
+        }
+
+        let mut total_amount: Balance = 0;
+        let formatted_donations = donations
+            .into_iter()
+            .map(|donation| {
+                total_amount += donation.amount.0;
+                let protocol_fee =
+                    self.calculate_fee(donation.amount.0, protocol_fee_basis_points, true);
+                self.record_donation(
+                    DonationArgs {
+                        donor_id: donor_id.clone(),
+                        sent_by: sent_by.clone(),
+                        deposit: donation.amount.0,
+                        project_id: Some(donation.project_id),
+                        message: donation.message,
+                        referrer_id: referrer_id.clone(),
+                        matching_pool: false,
+                        earmark: None,
+                        bypass_protocol_fee,
+                        custom_chef_fee_basis_points,
+                        currency: None,
+                    },
+                    protocol_fee,
+                    protocol_fee_recipient_account.clone(),
+                )
+            })
+            .collect();
+
+        // refund any deposit not allocated to the batch's donations
+        let unallocated = deposit - total_amount;
+        if unallocated > 0 {
+            Promise::new(sent_by).transfer(unallocated);
+        }
+        Some(formatted_donations)
+    }
+
+    /// Refunds caller's matching pool donations once the Pot has been cancelled
+    pub fn claim_matching_pool_refunds(&mut self) {
+        assert!(self.is_cancelled(), "Pot has not been cancelled");
+        let donor_id = env::predecessor_account_id();
+        let donation_ids = self
+            .donation_ids_by_donor_id
+            .get(&donor_id)
+            .expect("No donations for caller");
+        let unrefunded_donation_ids: Vec<DonationId> = donation_ids
+            .iter()
+            .filter(|donation_id| self.is_unrefunded_matching_pool_donation(donation_id))
+            .collect();
+        assert!(
+            !unrefunded_donation_ids.is_empty(),
+            "No matching pool donations to refund"
+        );
+        for donation_id in unrefunded_donation_ids {
+            self.refund_matching_pool_donation(donation_id);
+        }
+    }
+
+    /// Refunds up to `limit` matching pool donations (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT) starting from `from_index`, once the Pot has been cancelled (callable by admin or owner)
+    #[payable]
+    pub fn admin_process_matching_pool_refunds(
+        &mut self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) {
+        self.assert_admin_or_greater();
+        assert!(self.is_cancelled(), "Pot has not been cancelled");
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.matching_pool_donation_ids.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(DEFAULT_PAYOUTS_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        let unrefunded_donation_ids: Vec<DonationId> = self
+            .matching_pool_donation_ids
+            .iter()
+            .skip(start_index as usize)
+            .take(limit as usize)
+            .filter(|donation_id| self.is_unrefunded_matching_pool_donation(donation_id))
+            .collect();
+        for donation_id in unrefunded_donation_ids {
+            self.refund_matching_pool_donation(donation_id);
+        }
+    }
+
+    pub(crate) fn is_unrefunded_matching_pool_donation(&self, donation_id: &DonationId) -> bool {
+        self.matching_pool_donation_ids.contains(donation_id)
+            && Donation::from(self.donations_by_id.get(donation_id).expect("no donation"))
+                .refunded_at
+                .is_none()
+    }
+
+    /// Refunds donor's pro-rata share of the refundable matching pool balance, recording the refund on the donation
+    pub(crate) fn refund_matching_pool_donation(&mut self, donation_id: DonationId) {
+        let mut donation =
+            Donation::from(self.donations_by_id.get(&donation_id).expect("no donation"));
+        // forfeited challenge bonds are shared pro-rata between matching pool donations
+        let donated_amount = self.total_matching_pool_donations - self.forfeited_challenge_bonds;
+        let refund_amount = if donated_amount == 0 {
+            0
+        } else {
+            mul_div(
+                donation.net_amount,
+                self.matching_pool_refundable_balance,
+                donated_amount,
+            )
+        };
+        donation.refund_amount = Some(refund_amount);
+        donation.refunded_at = Some(env::block_timestamp_ms());
+        self.donations_by_id
+            .insert(&donation_id, &VersionedDonation::Current(donation.clone()));
+        if refund_amount > 0 {
+            self.transfer_funds(&donation.donor_id, refund_amount).then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(XCC_GAS)
+                    .transfer_matching_pool_refund_callback(donation_id),
+            );
+        }
+    }
+
+    /// Verifies whether refund transfer completed successfully & resets refund on donation record if not (so that it can be retried)
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn transfer_matching_pool_refund_callback(
+        &mut self,
+        donation_id: DonationId,
+        #[callback_result] call_result: Result<(), PromiseError>,
+    ) {
+        let mut donation =
+            Donation::from(self.donations_by_id.get(&donation_id).expect("no donation"));
+        if call_result.is_err() {
+            log!(format!(
+                "Error refunding amount {:#?} to donor {} for donation {}",
+                donation.refund_amount, donation.donor_id, donation_id
+            ));
+            donation.refund_amount = None;
+            donation.refunded_at = None;
+            self.donations_by_id
+                .insert(&donation_id, &VersionedDonation::Current(donation));
+        } else {
+            log!(format!(
+                "Successfully refunded amount {:#?} to donor {} for donation {}",
+                donation.refund_amount, donation.donor_id, donation_id
+            ));
+        }
+    }
+
+    /// Returns an error message if a public donation of `amount` (in the base currency) from `donor_id` to `project_id` would exceed the Pot's donation limits.
+    /// `pending_amount` is the amount the donor is donating to other projects earlier in the same batch (counted towards `max_per_donor`).
+    pub(crate) fn get_donation_limits_error(
+        &self,
+        donor_id: &AccountId,
+        project_id: &ProjectId,
+        amount: Balance,
+        pending_amount: Balance,
+    ) -> Option<String> {
+        let donation_limits = self.donation_limits.get()?;
+        if let Some(min_amount) = donation_limits.min_amount {
+            if amount < min_amount.0 {
+                return Some(format!(
+                    "Public donations must be at least {} ({})",
+                    min_amount.0, self.base_currency
+                ));
+            }
+        }
+        if let Some(max_amount) = donation_limits.max_amount {
+            if amount > max_amount.0 {
+                return Some(format!(
+                    "Public donations cannot exceed {} ({})",
+                    max_amount.0, self.base_currency
+                ));
+            }
+        }
+        if let Some(max_per_donor_per_project) = donation_limits.max_per_donor_per_project {
+            let donor_project_total = self
+                .donor_contributions_by_project
+                .get(&(project_id.clone(), donor_id.clone()))
+                .map(|contribution| contribution.total_amount)
+                .unwrap_or_default();
+            if donor_project_total + amount > max_per_donor_per_project.0 {
+                return Some(format!(
+                    "Total donations to project {} cannot exceed {} ({}) per donor",
+                    project_id, max_per_donor_per_project.0, self.base_currency
+                ));
+            }
+        }
+        if let Some(max_per_donor) = donation_limits.max_per_donor {
+            let donor_total = self
+                .public_donations_by_donor_id
+                .get(donor_id)
+                .unwrap_or_default();
+            if donor_total + pending_amount + amount > max_per_donor.0 {
+                return Some(format!(
+                    "Total donations cannot exceed {} ({}) per donor",
+                    max_per_donor.0, self.base_currency
+                ));
+            }
+        }
+        if let Some(max_per_project) = donation_limits.max_per_project {
+            let project_total = self
+                .project_stats_by_id
+                .get(project_id)
+                .map(|project_stats| ProjectStats::from(project_stats).total_donations)
+                .unwrap_or_default();
+            if project_total + amount > max_per_project.0 {
+                return Some(format!(
+                    "Total donations to project {} cannot exceed {} ({})",
+                    project_id, max_per_project.0, self.base_currency
+                ));
+            }
+        }
+        None
+    }
+
+    /// Returns the first donation limits error (if any) for a batch of public donations from `donor_id`
+    pub(crate) fn get_batch_donation_limits_error(
+        &self,
+        donor_id: &AccountId,
+        donations: &[BatchDonationItem],
+    ) -> Option<String> {
+        let mut pending_amount: Balance = 0;
+        for donation in donations.iter() {
+            let error = self.get_donation_limits_error(
+                donor_id,
+                &donation.project_id,
+                donation.amount.0,
+                pending_amount,
+            );
+            if error.is_some() {
+                return error;
+            }
+            pending_amount += donation.amount.0;
+        }
+        None
     }
 
     pub(crate) fn insert_donation_record(
@@ -678,6 +1656,10 @@ impl Contract {
             self.matching_pool_donation_ids.insert(donation_id);
         } else {
             self.public_round_donation_ids.insert(donation_id);
+            // update project's running stats
+            if let Some(project_id) = donation.project_id.as_ref() {
+                self.add_project_stats_donation(project_id, donation);
+            }
         }
     }
 
@@ -685,6 +1667,7 @@ impl Contract {
         DonationExternal {
             id,
             donor_id: donation.donor_id.clone(),
+            sent_by: donation.sent_by.clone(),
             total_amount: U128(donation.total_amount),
             net_amount: U128(donation.net_amount),
             message: donation.message.clone(),
@@ -696,6 +1679,12 @@ impl Contract {
             matching_pool: self.matching_pool_donation_ids.contains(&id),
             chef_id: donation.chef_id.clone(),
             chef_fee: donation.chef_fee.map(U128),
+            refund_amount: donation.refund_amount.map(U128),
+            refunded_at: donation.refunded_at,
+            sub_pool_id: donation.sub_pool_id,
+            currency: donation.currency.clone(),
+            normalized_total_amount: U128(donation.normalize(donation.total_amount)),
+            normalized_net_amount: U128(donation.normalize(donation.net_amount)),
         }
     }
 }
@@ -0,0 +1,169 @@
+use crate::*;
+
+#[near_bindgen]
+impl Contract {
+    /// Net public donations to `project_id` currently held in escrow (not yet released or refunded)
+    pub fn get_escrowed_donations_balance(&self, project_id: ProjectId) -> U128 {
+        U128(
+            self.escrowed_donations_by_project_id
+                .get(&project_id)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Refunds caller's escrowed public donations to projects that are no longer approved (or to any project, once the Pot has been cancelled)
+    /// Deliberately not pausable (see `PausableFeature`).
+    pub fn claim_escrowed_donation_refunds(&mut self) {
+        let donor_id = env::predecessor_account_id();
+        let donation_ids = self
+            .donation_ids_by_donor_id
+            .get(&donor_id)
+            .expect("No donations for caller");
+        let refundable_donation_ids: Vec<DonationId> = donation_ids
+            .iter()
+            .filter(|donation_id| self.is_refundable_escrowed_donation(donation_id))
+            .collect();
+        assert!(
+            !refundable_donation_ids.is_empty(),
+            "No escrowed donations to refund"
+        );
+        for donation_id in refundable_donation_ids {
+            self.refund_escrowed_donation(donation_id);
+        }
+    }
+
+    /// Refunds refundable escrowed public donations among up to `limit` public donations (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT) starting from `from_index` (callable by admin or owner). Deliberately not pausable (see `PausableFeature`).
+    #[payable]
+    pub fn admin_process_escrowed_donation_refunds(
+        &mut self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) {
+        self.assert_admin_or_greater();
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.public_round_donation_ids.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(DEFAULT_PAYOUTS_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        let refundable_donation_ids: Vec<DonationId> = self
+            .public_round_donation_ids
+            .iter()
+            .skip(start_index as usize)
+            .take(limit as usize)
+            .filter(|donation_id| self.is_refundable_escrowed_donation(donation_id))
+            .collect();
+        for donation_id in refundable_donation_ids {
+            self.refund_escrowed_donation(donation_id);
+        }
+    }
+
+    /// Verifies whether refund transfer completed successfully & returns the donation to escrow (and to the project's stats) if not (so that it can be retried)
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn transfer_escrowed_donation_refund_callback(
+        &mut self,
+        donation_id: DonationId,
+        #[callback_result] call_result: Result<(), PromiseError>,
+    ) {
+        let mut donation =
+            Donation::from(self.donations_by_id.get(&donation_id).expect("no donation"));
+        if call_result.is_err() {
+            log!(format!(
+                "Error refunding escrowed amount {:#?} to {} for donation {}",
+                donation.refund_amount, donation.donor_id, donation_id
+            ));
+            let project_id = donation.project_id.clone().expect("no project ID");
+            self.add_escrowed_donations(&project_id, donation.refund_amount.unwrap_or_default());
+            // donation counts towards stats & QF matching again
+            let normalized_net_amount = donation.normalize(donation.net_amount);
+            self.add_project_stats_donation(&project_id, &donation);
+            self.add_project_stats_net_amount(
+                &project_id,
+                &donation.donor_id,
+                normalized_net_amount,
+            );
+            self.total_public_donations += normalized_net_amount;
+            donation.refund_amount = None;
+            donation.refunded_at = None;
+            self.donations_by_id
+                .insert(&donation_id, &VersionedDonation::Current(donation));
+        } else {
+            log!(format!(
+                "Successfully refunded escrowed amount {:#?} to {} for donation {}",
+                donation.refund_amount, donation.donor_id, donation_id
+            ));
+        }
+    }
+
+    pub(crate) fn add_escrowed_donations(&mut self, project_id: &ProjectId, amount: Balance) {
+        let escrowed_amount = self
+            .escrowed_donations_by_project_id
+            .get(project_id)
+            .unwrap_or_default()
+            .checked_add(amount)
+            .expect("Overflow occurred when calculating escrowed donations balance");
+        self.escrowed_donations_by_project_id
+            .insert(project_id, &escrowed_amount);
+    }
+
+    /// Total net public donations currently held in escrow across all projects
+    pub(crate) fn get_total_escrowed_donations(&self) -> u128 {
+        self.escrowed_donations_by_project_id.values().sum()
+    }
+
+    /// An escrowed donation can be refunded if it has not already been refunded, its project's escrow has not been released, and the project is no longer approved (or the Pot has been cancelled)
+    pub(crate) fn is_refundable_escrowed_donation(&self, donation_id: &DonationId) -> bool {
+        if !self.public_round_donation_ids.contains(donation_id) {
+            return false;
+        }
+        let donation = Donation::from(self.donations_by_id.get(donation_id).expect("no donation"));
+        let project_id = donation.project_id.expect("no project ID");
+        donation.refunded_at.is_none()
+            && (self.is_cancelled() || !self.approved_application_ids.contains(&project_id))
+            && self
+                .escrowed_donations_by_project_id
+                .get(&project_id)
+                .unwrap_or_default()
+                > 0
+    }
+
+    /// Refunds the donation's net amount from its project's escrow to `donor_id` (the account the donation is attributed to, as with matching pool refunds), recording the refund on the donation & removing the donation from the project's stats (so that it no longer counts towards QF matching).
+    pub(crate) fn refund_escrowed_donation(&mut self, donation_id: DonationId) {
+        let mut donation =
+            Donation::from(self.donations_by_id.get(&donation_id).expect("no donation"));
+        let project_id = donation.project_id.clone().expect("no project ID");
+        let escrowed_amount = self
+            .escrowed_donations_by_project_id
+            .get(&project_id)
+            .unwrap_or_default();
+        let refund_amount = std::cmp::min(donation.net_amount, escrowed_amount);
+        if escrowed_amount > refund_amount {
+            self.escrowed_donations_by_project_id
+                .insert(&project_id, &(escrowed_amount - refund_amount));
+        } else {
+            self.escrowed_donations_by_project_id.remove(&project_id);
+        }
+        donation.refund_amount = Some(refund_amount);
+        donation.refunded_at = Some(env::block_timestamp_ms());
+        self.donations_by_id
+            .insert(&donation_id, &VersionedDonation::Current(donation.clone()));
+        let normalized_net_amount = donation.normalize(donation.net_amount);
+        self.subtract_project_stats_donation(&project_id, &donation);
+        self.subtract_project_stats_net_amount(
+            &project_id,
+            &donation.donor_id,
+            normalized_net_amount,
+        );
+        self.total_public_donations = self
+            .total_public_donations
+            .saturating_sub(normalized_net_amount);
+        if refund_amount > 0 {
+            self.transfer_funds(&donation.donor_id, refund_amount).then(
+                Self::ext(env::current_account_id())
+                    .with_static_gas(XCC_GAS)
+                    .transfer_escrowed_donation_refund_callback(donation_id),
+            );
+        }
+    }
+}
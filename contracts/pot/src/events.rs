@@ -41,3 +41,486 @@ pub(crate) fn log_update_pot_config_event(pot_config: &PotConfig) {
         .as_ref(),
     );
 }
+
+/// Features paused by owner/admin
+pub(crate) fn log_pause_event(paused_by: &AccountId, features: &[PausableFeature]) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "pause",
+                "data": [
+                    {
+                        "paused_by": paused_by,
+                        "features": features,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Features unpaused by owner/admin
+pub(crate) fn log_unpause_event(unpaused_by: &AccountId, features: &[PausableFeature]) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "unpause",
+                "data": [
+                    {
+                        "unpaused_by": unpaused_by,
+                        "features": features,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Manual adjustment of calculated payouts
+pub(crate) fn log_adjust_payouts_event(
+    adjusted_by: &AccountId,
+    justification: &str,
+    adjustments: &[PayoutAdjustment],
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "adjust_payouts",
+                "data": [
+                    {
+                        "adjusted_by": adjusted_by,
+                        "justification": justification,
+                        "adjustments": adjustments,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Leftover Pot balance swept to remainder recipient (or rolled over into another Pot's matching pool)
+pub(crate) fn log_sweep_remainder_event(recipient_id: &AccountId, amount: u128, rollover: bool) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "sweep_remainder",
+                "data": [
+                    {
+                        "recipient_id": recipient_id,
+                        "amount": U128(amount),
+                        "rollover": rollover,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Application submitted
+pub(crate) fn log_apply_event(application: &Application) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "apply",
+                "data": [
+                    {
+                        "application": application,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Application withdrawn by applicant
+pub(crate) fn log_unapply_event(project_id: &ProjectId) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "unapply",
+                "data": [
+                    {
+                        "project_id": project_id,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Project set (or removed) the account its payouts are transferred to
+pub(crate) fn log_update_payout_recipient_event(
+    project_id: &ProjectId,
+    payout_recipient: &Option<AccountId>,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "update_payout_recipient",
+                "data": [
+                    {
+                        "project_id": project_id,
+                        "payout_recipient": payout_recipient,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Application status changed by chef (or admin/owner), or by reviewer votes reaching quorum
+pub(crate) fn log_update_application_status_event(
+    project_id: &ProjectId,
+    review: &ApplicationReview,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "update_application_status",
+                "data": [
+                    {
+                        "project_id": project_id,
+                        "review": review,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Owner/admin disqualified a project
+pub(crate) fn log_disqualify_project_event(
+    disqualified_by: &AccountId,
+    project_id: &ProjectId,
+    reason: &str,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "disqualify_project",
+                "data": [
+                    {
+                        "project_id": project_id,
+                        "disqualified_by": disqualified_by,
+                        "reason": reason,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Chef/admin set (or removed) an application's category
+pub(crate) fn log_set_application_category_event(
+    project_id: &ProjectId,
+    category: &Option<String>,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "set_application_category",
+                "data": [
+                    {
+                        "project_id": project_id,
+                        "category": category,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Chef (or owner/admin) created a matching sub-pool
+pub(crate) fn log_create_matching_sub_pool_event(
+    created_by: &AccountId,
+    sub_pool: &MatchingSubPoolExternal,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "create_matching_sub_pool",
+                "data": [
+                    {
+                        "created_by": created_by,
+                        "sub_pool": sub_pool,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Reviewer voted on an application
+pub(crate) fn log_application_vote_event(project_id: &ProjectId, vote: &ApplicationVote) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "application_vote",
+                "data": [
+                    {
+                        "project_id": project_id,
+                        "vote": vote,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Donation (public round or matching pool) recorded
+pub(crate) fn log_donation_event(donation: &DonationExternal) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "donation",
+                "data": [
+                    {
+                        "donation": donation,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Payouts set by chef (or admin/owner)
+pub(crate) fn log_set_payouts_event(
+    set_by: &AccountId,
+    payouts: &[PayoutExternal],
+    cooldown_end_ms: Option<TimestampMs>,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "set_payouts",
+                "data": [
+                    {
+                        "set_by": set_by,
+                        "payouts": payouts,
+                        "cooldown_end_ms": cooldown_end_ms,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Payout transfer completed (successfully or not)
+pub(crate) fn log_payout_transfer_event(
+    payout: &PayoutExternal,
+    recipient_id: &AccountId,
+    success: bool,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "payout_transfer",
+                "data": [
+                    {
+                        "payout": payout,
+                        "recipient_id": recipient_id,
+                        "success": success,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Payout released into a vesting stream (instead of being transferred in full)
+pub(crate) fn log_start_payout_vesting_event(payout: &PayoutExternal) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "start_payout_vesting",
+                "data": [
+                    {
+                        "payout": payout,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Project withdrew vested payout funds
+pub(crate) fn log_withdraw_vested_event(
+    project_id: &ProjectId,
+    recipient_id: &AccountId,
+    amount: u128,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "withdraw_vested",
+                "data": [
+                    {
+                        "project_id": project_id,
+                        "recipient_id": recipient_id,
+                        "amount": U128(amount),
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Owner/admin terminated a payout's vesting stream
+pub(crate) fn log_terminate_payout_vesting_event(
+    terminated_by: &AccountId,
+    payout: &PayoutExternal,
+    unvested_amount: u128,
+    remainder_recipient: Option<&AccountId>,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "terminate_payout_vesting",
+                "data": [
+                    {
+                        "payout": payout,
+                        "terminated_by": terminated_by,
+                        "unvested_amount": U128(unvested_amount),
+                        "remainder_recipient": remainder_recipient,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Payouts challenged during cooldown period
+pub(crate) fn log_challenge_created_event(challenge: &PayoutsChallengeExternal) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "challenge_created",
+                "data": [
+                    {
+                        "challenge": challenge,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// Payouts challenge resolved by admin/owner
+pub(crate) fn log_challenge_resolved_event(
+    resolved_by: &AccountId,
+    challenge: &PayoutsChallengeExternal,
+) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "challenge_resolved",
+                "data": [
+                    {
+                        "resolved_by": resolved_by,
+                        "challenge": challenge,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
@@ -8,6 +8,46 @@ impl Contract {
         );
     }
 
+    pub(crate) fn is_base_currency_near(&self) -> bool {
+        self.base_currency.as_str() == NEAR_BASE_CURRENCY
+    }
+
+    /// Returns true if a donation in `currency` (None for the Pot's base currency) was made in NEAR
+    pub(crate) fn is_near_donation(&self, currency: &Option<DonationCurrency>) -> bool {
+        currency
+            .as_ref()
+            .map_or(self.is_base_currency_near(), |currency| {
+                currency.currency.as_str() == NEAR_BASE_CURRENCY
+            })
+    }
+
+    /// Transfers `amount` of the Pot's base currency (NEAR or NEP-141 FT) to `recipient_id`
+    pub(crate) fn transfer_funds(&self, recipient_id: &AccountId, amount: Balance) -> Promise {
+        self.transfer_currency(&self.base_currency, recipient_id, amount)
+    }
+
+    /// Transfers `amount` of `currency_id` ("near" or NEP-141 FT contract ID) to `recipient_id`
+    pub(crate) fn transfer_currency(
+        &self,
+        currency_id: &AccountId,
+        recipient_id: &AccountId,
+        amount: Balance,
+    ) -> Promise {
+        if currency_id.as_str() == NEAR_BASE_CURRENCY {
+            Promise::new(recipient_id.clone()).transfer(amount)
+        } else {
+            let ft_transfer_args = json!({ "receiver_id": recipient_id, "amount": U128(amount) })
+                .to_string()
+                .into_bytes();
+            Promise::new(currency_id.clone()).function_call(
+                "ft_transfer".to_string(),
+                ft_transfer_args,
+                ONE_YOCTO,
+                FT_TRANSFER_GAS,
+            )
+        }
+    }
+
     pub(crate) fn is_owner(&self, account_id: Option<&AccountId>) -> bool {
         account_id.unwrap_or(&env::predecessor_account_id()) == &self.owner
     }
@@ -100,6 +140,23 @@ impl Contract {
         }
     }
 
+    /// Whether any batch of payouts has been processed, or (if payouts are claimable) projects can start claiming payouts. At this point payouts can no longer be reset.
+    pub(crate) fn is_payouts_processing_started(&self) -> bool {
+        self.payouts_processed_index > 0
+            || self.all_paid_out
+            || (self.payouts_claimable
+                && self
+                    .cooldown_end_ms
+                    .get()
+                    .is_some_and(|cooldown_end_ms| cooldown_end_ms < env::block_timestamp_ms()))
+    }
+
+    pub(crate) fn is_claim_deadline_passed(&self) -> bool {
+        self.claim_deadline_ms
+            .get()
+            .is_some_and(|claim_deadline_ms| claim_deadline_ms <= env::block_timestamp_ms())
+    }
+
     pub(crate) fn assert_all_payouts_challenges_resolved(&self) {
         for (challenger_id, versioned_payouts_challenge) in self.payouts_challenges.iter() {
             let payouts_challenge = PayoutsChallenge::from(versioned_payouts_challenge);
@@ -128,6 +185,40 @@ impl Contract {
         assert!(self.is_round_active(), "Public round is not active");
     }
 
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.cancelled_at_ms.get().is_some()
+    }
+
+    pub(crate) fn assert_not_cancelled(&self) {
+        assert!(!self.is_cancelled(), "Pot has been cancelled");
+    }
+
+    pub(crate) fn assert_not_paused(&self, feature: PausableFeature) {
+        assert!(
+            !self.paused_features.contains(&feature),
+            "{:?} are currently paused",
+            feature
+        );
+    }
+
+    /// Returns the account a donation should be attributed to. Defaults to the account sending the funds; only trusted intermediaries may donate on behalf of another account.
+    pub(crate) fn resolve_donor_id(
+        &self,
+        sent_by: &AccountId,
+        on_behalf_of: Option<AccountId>,
+    ) -> AccountId {
+        match on_behalf_of {
+            Some(donor_id) if &donor_id != sent_by => {
+                assert!(
+                    self.trusted_intermediaries.contains(sent_by),
+                    "Only trusted intermediaries can donate on behalf of another account"
+                );
+                donor_id
+            }
+            _ => sent_by.clone(),
+        }
+    }
+
     pub(crate) fn assert_max_projects_not_reached(&self) {
         assert!(
             self.approved_application_ids.len() < self.max_projects.into(),
@@ -1,12 +1,12 @@
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
+use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet, Vector};
 use near_sdk::json_types::U128;
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{
     env, log, near_bindgen, require, serde_json::json, AccountId, Balance, BorshStorageKey, Gas,
-    PanicOnDefault, Promise, PromiseError, PromiseOrValue,
+    PanicOnDefault, Promise, PromiseError, PromiseOrValue, PromiseResult,
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 type TimestampMs = u64;
 
@@ -15,12 +15,18 @@ pub mod applications;
 pub mod config;
 pub mod constants;
 pub mod donations;
+pub mod escrow;
 pub mod events;
 pub mod internal;
+pub mod pause;
 pub mod payouts;
 pub mod source;
+pub mod stats;
+pub mod storage;
+pub mod sub_pools;
 pub mod utils;
 pub mod validation;
+pub mod vesting;
 pub use crate::admin::*;
 pub use crate::applications::*;
 pub use crate::config::*;
@@ -28,10 +34,15 @@ pub use crate::constants::*;
 pub use crate::donations::*;
 pub use crate::events::*;
 pub use crate::internal::*;
+pub use crate::pause::*;
 pub use crate::payouts::*;
 pub use crate::source::*;
+pub use crate::stats::*;
+pub use crate::storage::*;
+pub use crate::sub_pools::*;
 pub use crate::utils::*;
 pub use crate::validation::*;
+pub use crate::vesting::*;
 
 // TODO: move Provider stuff elsewhere?
 #[derive(
@@ -111,6 +122,8 @@ pub struct Contract {
     /// Address (ID) of Pot manager ("chef"). This account is responsible for managing the Pot, e.g. reviewing applications, setting payouts, etc.
     /// Optional because it may be set after deployment.
     chef: LazyOption<AccountId>,
+    /// Accounts that vote on applications. Once `review_quorum` matching votes are cast, the application is approved or rejected automatically.
+    reviewers: UnorderedSet<AccountId>,
 
     // POT CONFIG
     /// User-facing name for this Pot
@@ -119,13 +132,21 @@ pub struct Contract {
     pot_description: String,
     /// Maximum number of projects that can be approved for the round. Considerations include gas limits for payouts, etc.
     max_projects: u32,
-    /// Base currency for the round
-    /// * NB: currently only `"near"` is supported
+    /// Base currency for the round; either `"near"` or the account ID of a NEP-141 fungible token contract
     base_currency: AccountId,
+    /// Additional currencies (`"near"` or NEP-141 contract IDs) accepted for public round donations. Donations in these currencies are normalized into the base currency (via `price_oracle_provider`) for donation limits, stats & matching calculations.
+    accepted_currencies: UnorderedSet<AccountId>,
+    /// Contract ID + method name of price oracle provider that should be queried for the price of an accepted currency in the base currency. Method specified must receive "currency" and "base_currency" and return struct containing multiplier and decimals.
+    /// * Required if `accepted_currencies` is not empty.
+    price_oracle_provider: LazyOption<ProviderId>,
     /// MS Timestamp when applications can be submitted from
     application_start_ms: TimestampMs,
     /// MS Timestamp when applications can be submitted until
     application_end_ms: TimestampMs,
+    /// Questions that applicants must answer when applying (if any)
+    application_schema: LazyOption<Vec<ApplicationQuestion>>,
+    /// Number of matching reviewer votes required to approve or reject an application. Defaults to a simple majority of reviewers if not set.
+    review_quorum: LazyOption<u32>,
     /// MS Timestamp when the public round starts
     public_round_start_ms: TimestampMs,
     /// MS Timestamp when the round ends
@@ -137,6 +158,12 @@ pub struct Contract {
     registry_provider: LazyOption<ProviderId>,
     /// Minimum amount that can be donated to the matching pool
     min_matching_pool_donation_amount: u128,
+    /// Limits on public round donations (per donation, per donor, per project). If `None`, no limits are enforced.
+    donation_limits: LazyOption<DonationLimits>,
+    /// If true, public donations (after fees) are held in escrow by the Pot & released to projects alongside matching funds when payouts are processed, rather than transferred immediately
+    escrow_public_donations: bool,
+    /// Maximum matching (in base currency) that a pair of donors can generate across all projects they have both donated to, as in pairwise-bounded CLR
+    pairwise_match_threshold: Balance,
 
     // SYBIL RESISTANCE
     /// Sybil contract address & method name that will be called to verify humanness. If `None`, no checks will be made.
@@ -159,22 +186,76 @@ pub struct Contract {
     total_matching_pool_donations: u128,
     /// Amount of matching funds available (not yet paid out)
     matching_pool_balance: u128,
+    /// Portions of the matching pool earmarked for a subset of projects (balances are included in `matching_pool_balance`)
+    matching_sub_pools_by_id: UnorderedMap<SubPoolId, VersionedMatchingSubPool>,
     /// Total public donations
     total_public_donations: u128,
+    /// Amount of matching pool available for refunds to matching pool donors (set when Pot is cancelled)
+    matching_pool_refundable_balance: u128,
+    /// Storage deposits (in NEAR) made by donors, used to cover storage of FT donations
+    storage_deposits: UnorderedMap<AccountId, Balance>,
+    /// Net public donations held in escrow for each project (only applicable if `escrow_public_donations` is true)
+    escrowed_donations_by_project_id: UnorderedMap<ProjectId, Balance>,
 
     // PAYOUTS
     /// Length of cooldown period (in ms) after which payouts can be set by Chef
     cooldown_period_ms: u64,
     /// Cooldown period starts when Chef sets payouts
     cooldown_end_ms: LazyOption<TimestampMs>,
-    /// Indicates whether all projects been paid out (this would be considered the "end-of-lifecycle" for the Pot)
+    /// Bond (in NEAR) that must be attached when challenging payouts. Refunded if the challenge is upheld (or withdrawn), forfeited if dismissed.
+    payouts_challenge_bond: Balance,
+    /// Total bonds forfeited to the matching pool by dismissed payouts challenges (NEAR Pots only; included in `total_matching_pool_donations`, but not attributed to any matching pool donation)
+    forfeited_challenge_bonds: Balance,
+    /// Total payout amounts reclaimed from projects (clawed back milestone tranches that were not redirected, unclaimed payouts swept after the claim deadline & unvested remainders of terminated vesting streams), which are no longer owed to projects. Reclaimed amounts remain in the Pot & are swept with the remainder, unless transferred to `remainder_recipient` when vesting is terminated. Reset when payouts are cleared.
+    reclaimed_payouts_balance: Balance,
+    /// Total leftover balance transferred to `remainder_recipient` (swept remainders & unvested remainders of terminated vesting streams), which is no longer available for matching pool refunds
+    swept_remainder_amount: Balance,
+    /// If true, payouts were changed since the chef set them (e.g. a project was disqualified & its payouts removed), and should be reviewed by the chef (who may set them again). Reset when payouts are cleared.
+    payouts_review_required: bool,
+    /// Incrementing index used to generate payout IDs, so that IDs are never reused (even once payouts are replaced or cleared)
+    next_payout_index: u64,
+    /// If true, payouts are claimed by each project via `claim_payout` rather than transferred via `admin_process_payouts`
+    payouts_claimable: bool,
+    /// Deadline after which unclaimed payouts can be swept back into the matching pool (only applicable if payouts are claimable)
+    claim_deadline_ms: LazyOption<TimestampMs>,
+    /// If set, released matching fund payouts vest on this schedule & are withdrawn by projects via `withdraw_vested`, rather than being transferred in full
+    payout_vesting_schedule: LazyOption<PayoutVestingSchedule>,
+    /// Payouts that are being set in batches & have not yet been finalized. None once payouts have been finalized (or if they have not been set).
+    payouts_draft: LazyOption<PayoutsDraft>,
+    /// Index (into `payout_ids`) up to which payouts have been processed; payouts are processed in batches, resuming from this index
+    payouts_processed_index: u64,
+    /// Number of payout transfers that have been initiated & whose callbacks have not yet been received
+    payout_transfers_in_flight: u64,
+    /// Indicates whether all projects been paid out (this would be considered the "end-of-lifecycle" for the Pot). Set once every payout has been processed & none is pending (awaiting milestone completion), in flight or failed.
     all_paid_out: bool,
+    /// Progress of the (batched) on-chain QF calculation. None if it has not been started.
+    qf_calculation: LazyOption<QfCalculation>,
+    /// Running pair totals of the QF calculation, keyed by pair of donors (in canonical order)
+    qf_pair_totals: UnorderedMap<(AccountId, AccountId), QfPairTotal>,
+    /// Matching scores of the QF calculation, by project
+    qf_project_scores: UnorderedMap<ProjectId, Balance>,
+    /// Matching amounts allocated by the QF calculation, by project
+    qf_matching_amounts: UnorderedMap<ProjectId, Balance>,
+    /// Running totals used to allocate each earmarked sub-pool in the QF calculation, by sub-pool (only sub-pools with eligible approved projects are included)
+    qf_sub_pool_allocations: UnorderedMap<SubPoolId, QfSubPoolAllocation>,
+    /// Account that any leftover Pot balance is swept to once payouts are complete (may be another Pot deployed by the same factory, in which case the leftover balance is rolled over into its matching pool)
+    remainder_recipient: LazyOption<AccountId>,
+    /// Timestamp when the Pot was cancelled by owner/admin. Once cancelled, donations, applications & payouts are frozen and matching pool donations can be refunded.
+    cancelled_at_ms: LazyOption<TimestampMs>,
+    /// Features (donations, applications, payouts) currently paused by owner/admin, e.g. while a bug is investigated
+    paused_features: UnorderedSet<PausableFeature>,
+    /// Intermediary contracts (e.g. DAOs, donation routers) allowed to donate on behalf of other accounts via `on_behalf_of`
+    trusted_intermediaries: UnorderedSet<AccountId>,
 
     // MAPPINGS
     /// All application records
     applications_by_id: UnorderedMap<ApplicationId, VersionedApplication>,
     /// Approved application IDs
     approved_application_ids: UnorderedSet<ApplicationId>,
+    /// Votes cast by reviewers on each application (one per reviewer; a reviewer may change their vote)
+    application_votes_by_project_id: LookupMap<ProjectId, UnorderedMap<AccountId, ApplicationVote>>,
+    /// Append-only log of all status changes made to each application
+    application_reviews_by_project_id: LookupMap<ProjectId, Vector<ApplicationReview>>,
     /// All donation records
     donations_by_id: UnorderedMap<DonationId, VersionedDonation>,
     /// IDs of public round donations (made by donors who are not Patrons, during public round)
@@ -185,9 +266,23 @@ pub struct Contract {
     donation_ids_by_project_id: LookupMap<ProjectId, UnorderedSet<DonationId>>,
     /// IDs of donations made by a given donor (user)
     donation_ids_by_donor_id: LookupMap<AccountId, UnorderedSet<DonationId>>,
+    /// Running aggregates of public donations to each project
+    project_stats_by_id: UnorderedMap<ProjectId, VersionedProjectStats>,
+    /// Public donations made by a given donor to a given project
+    donor_contributions_by_project: LookupMap<(ProjectId, AccountId), DonorContribution>,
+    /// Total (gross) public donations made by a given donor across all projects
+    public_donations_by_donor_id: LookupMap<AccountId, Balance>,
+    /// Public donors to a given project, in order of their first donation (so that pairs of donors can be iterated over in batches)
+    donor_ids_by_project_id: LookupMap<ProjectId, Vector<AccountId>>,
     // payouts
     payouts_by_id: UnorderedMap<PayoutId, VersionedPayout>, // can iterate over this to get all payouts
+    /// IDs of payouts in order of creation, so that payouts can be processed (or cleared) in batches in a stable order. IDs of removed payouts (e.g. of a disqualified project) are skipped.
+    payout_ids: Vector<PayoutId>,
     payout_ids_by_project_id: LookupMap<ProjectId, UnorderedSet<PayoutId>>,
+    /// IDs of payouts whose transfers failed and have not yet been retried
+    failed_payout_ids: UnorderedSet<PayoutId>,
+    /// IDs of payouts passed over by payouts processing because their milestone had not been completed, which are still to be released (or clawed back)
+    pending_payout_ids: UnorderedSet<PayoutId>,
     /// Challenges to payouts (if any) made during cooldown period
     payouts_challenges: UnorderedMap<AccountId, VersionedPayoutsChallenge>,
 
@@ -224,46 +319,178 @@ pub enum StorageKey {
     PayoutIdsByProjectId,
     PayoutIdsByProjectIdInner { project_id: ProjectId },
     PayoutsChallenges,
+    StorageDeposits,
+    FailedPayoutIds,
+    ClaimDeadlineMs,
+    CancelledAtMs,
+    RemainderRecipient,
+    ApplicationSchema,
+    DonationLimits,
+    TotalPublicDonationsByProjectId, // retained (unused) so that subsequent keys keep their storage prefixes; per-project donation totals are now tracked in `project_stats_by_id`
+    ProjectStatsById,
+    DonorContributionsByProject,
+    Reviewers,
+    ReviewQuorum,
+    PausedFeatures,
+    TrustedIntermediaries,
+    MatchingSubPoolsById,
+    EscrowedDonationsByProjectId,
+    PayoutVestingSchedule,
+    AcceptedCurrencies,
+    PriceOracleProvider,
+    PublicDonationsByDonorId,
+    QfCalculation,
+    QfPairTotals,
+    QfProjectScores,
+    DonorIdsByProjectId,
+    DonorIdsByProjectIdInner { project_id: ProjectId },
+    ApplicationVotesByProjectId,
+    ApplicationVotesByProjectIdInner { project_id: ProjectId },
+    ApplicationReviewsByProjectId,
+    ApplicationReviewsByProjectIdInner { project_id: ProjectId },
+    QfMatchingAmounts,
+    QfSubPoolAllocations,
+    PayoutsDraft,
+    PayoutIds,
+    PendingPayoutIds,
+}
+
+/// Arguments for initializing a new Pot (see `Contract::new`); these must be kept up-to-date with the Pot Factory's `PotArgs`
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PotArgs {
+    // permissioned accounts
+    /// Defaults to signer account if not provided
+    pub owner: Option<AccountId>,
+    pub admins: Option<Vec<AccountId>>,
+    pub chef: Option<AccountId>,
+    pub reviewers: Option<Vec<AccountId>>,
+
+    // pot config
+    pub pot_name: String,
+    pub pot_description: String,
+    pub max_projects: u32,
+    /// Defaults to "near" if not provided
+    pub base_currency: Option<AccountId>,
+    /// Defaults to none (only base currency is accepted)
+    pub accepted_currencies: Option<Vec<AccountId>>,
+    pub price_oracle_provider: Option<ProviderId>,
+    pub application_start_ms: TimestampMs,
+    pub application_end_ms: TimestampMs,
+    pub application_schema: Option<Vec<ApplicationQuestion>>,
+    /// Defaults to simple majority of reviewers
+    pub review_quorum: Option<u32>,
+    pub public_round_start_ms: TimestampMs,
+    pub public_round_end_ms: TimestampMs,
+    pub registry_provider: Option<ProviderId>,
+    pub min_matching_pool_donation_amount: Option<U128>,
+    pub donation_limits: Option<DonationLimits>,
+    /// Defaults to false (public donations are transferred to projects immediately)
+    pub escrow_public_donations: Option<bool>,
+    /// Defaults to DEFAULT_PAIRWISE_MATCH_THRESHOLD (should be set for FT base currencies)
+    pub pairwise_match_threshold: Option<U128>,
+    pub cooldown_period_ms: Option<u64>,
+    /// Defaults to DEFAULT_PAYOUTS_CHALLENGE_BOND
+    pub payouts_challenge_bond: Option<U128>,
+    /// Defaults to false (payouts are transferred by admin)
+    pub payouts_claimable: Option<bool>,
+    pub claim_deadline_ms: Option<TimestampMs>,
+    /// Defaults to None (payouts are transferred in full)
+    pub payout_vesting_schedule: Option<PayoutVestingSchedule>,
+    pub remainder_recipient: Option<AccountId>,
+
+    // sybil resistance
+    pub sybil_wrapper_provider: Option<ProviderId>,
+    pub custom_sybil_checks: Option<HashMap<ProviderId, SybilProviderWeight>>,
+    pub custom_min_threshold_score: Option<u32>,
+
+    // fees
+    /// This could be optional with a default, but better to set explicitly for now
+    pub referral_fee_matching_pool_basis_points: u32,
+    /// This could be optional with a default, but better to set explicitly for now
+    pub referral_fee_public_round_basis_points: u32,
+    pub chef_fee_basis_points: u32,
+
+    // other
+    pub protocol_config_provider: Option<ProviderId>,
+    pub source_metadata: ContractSourceMetadata,
 }
 
 #[near_bindgen]
 impl Contract {
     #[init]
-    pub fn new(
-        // permissioned accounts
-        owner: Option<AccountId>, // defaults to signer account if not provided
-        admins: Option<Vec<AccountId>>,
-        chef: Option<AccountId>,
-
-        // pot config
-        pot_name: String,
-        pot_description: String,
-        max_projects: u32,
-        application_start_ms: TimestampMs,
-        application_end_ms: TimestampMs,
-        public_round_start_ms: TimestampMs,
-        public_round_end_ms: TimestampMs,
-        registry_provider: Option<ProviderId>,
-        min_matching_pool_donation_amount: Option<U128>,
-        cooldown_period_ms: Option<u64>,
-
-        // sybil resistance
-        sybil_wrapper_provider: Option<ProviderId>,
-        custom_sybil_checks: Option<HashMap<ProviderId, SybilProviderWeight>>,
-        custom_min_threshold_score: Option<u32>,
-
-        // fees
-        referral_fee_matching_pool_basis_points: u32, // this could be optional with a default, but better to set explicitly for now
-        referral_fee_public_round_basis_points: u32, // this could be optional with a default, but better to set explicitly for now
-        chef_fee_basis_points: u32,
-
-        // other
-        protocol_config_provider: Option<ProviderId>,
-        source_metadata: ContractSourceMetadata,
-    ) -> Self {
+    pub fn new(pot_args: PotArgs) -> Self {
+        let PotArgs {
+            owner,
+            admins,
+            chef,
+            reviewers,
+            pot_name,
+            pot_description,
+            max_projects,
+            base_currency,
+            accepted_currencies,
+            price_oracle_provider,
+            application_start_ms,
+            application_end_ms,
+            application_schema,
+            review_quorum,
+            public_round_start_ms,
+            public_round_end_ms,
+            registry_provider,
+            min_matching_pool_donation_amount,
+            donation_limits,
+            escrow_public_donations,
+            pairwise_match_threshold,
+            cooldown_period_ms,
+            payouts_challenge_bond,
+            payouts_claimable,
+            claim_deadline_ms,
+            payout_vesting_schedule,
+            remainder_recipient,
+            sybil_wrapper_provider,
+            custom_sybil_checks,
+            custom_min_threshold_score,
+            referral_fee_matching_pool_basis_points,
+            referral_fee_public_round_basis_points,
+            chef_fee_basis_points,
+            protocol_config_provider,
+            source_metadata,
+        } = pot_args;
         if let Some(cooldown_period_ms) = cooldown_period_ms {
             assert_valid_cooldown_period_ms(cooldown_period_ms);
         }
+        if let Some(pairwise_match_threshold) = pairwise_match_threshold {
+            assert_valid_pairwise_match_threshold(pairwise_match_threshold.0);
+        }
+        if let Some(custom_sybil_checks) = &custom_sybil_checks {
+            assert_valid_custom_sybil_checks_count(custom_sybil_checks.len());
+        }
+        if let Some(application_schema) = &application_schema {
+            assert_valid_application_schema(application_schema);
+        }
+        if let Some(donation_limits) = &donation_limits {
+            assert_valid_donation_limits(donation_limits);
+        }
+        let reviewers = reviewers.unwrap_or_default();
+        if let Some(review_quorum) = review_quorum {
+            assert_valid_review_quorum(review_quorum, reviewers.len() as u64);
+        }
+        if let Some(claim_deadline_ms) = claim_deadline_ms {
+            assert_valid_claim_deadline_ms(claim_deadline_ms, public_round_end_ms);
+        }
+        if let Some(payout_vesting_schedule) = &payout_vesting_schedule {
+            assert_valid_payout_vesting_schedule(payout_vesting_schedule);
+        }
+        let base_currency =
+            base_currency.unwrap_or(AccountId::new_unchecked(NEAR_BASE_CURRENCY.to_string()));
+        let accepted_currencies = accepted_currencies.unwrap_or_default();
+        assert_valid_accepted_currencies(
+            &accepted_currencies,
+            &base_currency,
+            price_oracle_provider.as_ref(),
+            escrow_public_donations.unwrap_or(false),
+        );
         Self {
             // permissioned accounts
             owner: owner.unwrap_or(env::signer_account_id()),
@@ -276,14 +503,28 @@ impl Contract {
                 StorageKey::Admins,
             ),
             chef: LazyOption::new(StorageKey::Chef, chef.as_ref()),
+            reviewers: account_vec_to_set(reviewers, StorageKey::Reviewers),
 
             // pot config
             pot_name,
             pot_description,
             max_projects,
-            base_currency: AccountId::new_unchecked("near".to_string()),
+            base_currency,
+            accepted_currencies: account_vec_to_set(
+                accepted_currencies,
+                StorageKey::AcceptedCurrencies,
+            ),
+            price_oracle_provider: LazyOption::new(
+                StorageKey::PriceOracleProvider,
+                price_oracle_provider.as_ref(),
+            ),
             application_start_ms,
             application_end_ms,
+            application_schema: LazyOption::new(
+                StorageKey::ApplicationSchema,
+                application_schema.as_ref(),
+            ),
+            review_quorum: LazyOption::new(StorageKey::ReviewQuorum, review_quorum.as_ref()),
             public_round_start_ms,
             public_round_end_ms,
             deployed_by: env::signer_account_id(),
@@ -294,6 +535,11 @@ impl Contract {
             min_matching_pool_donation_amount: min_matching_pool_donation_amount
                 .unwrap_or(U128(1))
                 .into(), // default to 1 YoctoNEAR
+            donation_limits: LazyOption::new(StorageKey::DonationLimits, donation_limits.as_ref()),
+            escrow_public_donations: escrow_public_donations.unwrap_or(false),
+            pairwise_match_threshold: pairwise_match_threshold
+                .map(|threshold| threshold.0)
+                .unwrap_or(DEFAULT_PAIRWISE_MATCH_THRESHOLD),
 
             // sybil resistance
             sybil_wrapper_provider: LazyOption::new(
@@ -317,23 +563,74 @@ impl Contract {
             // funds and balances
             total_matching_pool_donations: 0,
             matching_pool_balance: 0,
+            matching_sub_pools_by_id: UnorderedMap::new(StorageKey::MatchingSubPoolsById),
             total_public_donations: 0,
+            matching_pool_refundable_balance: 0,
+            storage_deposits: UnorderedMap::new(StorageKey::StorageDeposits),
+            escrowed_donations_by_project_id: UnorderedMap::new(
+                StorageKey::EscrowedDonationsByProjectId,
+            ),
 
             // payouts
             cooldown_period_ms: cooldown_period_ms.unwrap_or(DEFAULT_COOLDOWN_PERIOD_MS),
             cooldown_end_ms: LazyOption::new(StorageKey::CooldownEndMs, None),
+            payouts_challenge_bond: payouts_challenge_bond
+                .map(|bond| bond.0)
+                .unwrap_or(DEFAULT_PAYOUTS_CHALLENGE_BOND),
+            forfeited_challenge_bonds: 0,
+            reclaimed_payouts_balance: 0,
+            swept_remainder_amount: 0,
+            payouts_review_required: false,
+            next_payout_index: 0,
+            payouts_claimable: payouts_claimable.unwrap_or(false),
+            claim_deadline_ms: LazyOption::new(
+                StorageKey::ClaimDeadlineMs,
+                claim_deadline_ms.as_ref(),
+            ),
+            payout_vesting_schedule: LazyOption::new(
+                StorageKey::PayoutVestingSchedule,
+                payout_vesting_schedule.as_ref(),
+            ),
+            payouts_draft: LazyOption::new(StorageKey::PayoutsDraft, None),
+            payouts_processed_index: 0,
+            payout_transfers_in_flight: 0,
             all_paid_out: false,
+            qf_calculation: LazyOption::new(StorageKey::QfCalculation, None),
+            qf_pair_totals: UnorderedMap::new(StorageKey::QfPairTotals),
+            qf_project_scores: UnorderedMap::new(StorageKey::QfProjectScores),
+            qf_matching_amounts: UnorderedMap::new(StorageKey::QfMatchingAmounts),
+            qf_sub_pool_allocations: UnorderedMap::new(StorageKey::QfSubPoolAllocations),
+            remainder_recipient: LazyOption::new(
+                StorageKey::RemainderRecipient,
+                remainder_recipient.as_ref(),
+            ),
+            cancelled_at_ms: LazyOption::new(StorageKey::CancelledAtMs, None),
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
+            trusted_intermediaries: UnorderedSet::new(StorageKey::TrustedIntermediaries),
 
             // mappings
             applications_by_id: UnorderedMap::new(StorageKey::ApplicationsById),
             approved_application_ids: UnorderedSet::new(StorageKey::ApprovedApplicationIds),
+            application_votes_by_project_id: LookupMap::new(
+                StorageKey::ApplicationVotesByProjectId,
+            ),
+            application_reviews_by_project_id: LookupMap::new(
+                StorageKey::ApplicationReviewsByProjectId,
+            ),
             donations_by_id: UnorderedMap::new(StorageKey::DonationsById),
             public_round_donation_ids: UnorderedSet::new(StorageKey::PublicRoundDonationIds),
             matching_pool_donation_ids: UnorderedSet::new(StorageKey::MatchingPoolDonationIds),
             donation_ids_by_project_id: LookupMap::new(StorageKey::DonationIdsByProjectId),
             donation_ids_by_donor_id: LookupMap::new(StorageKey::DonationIdsByDonorId),
+            project_stats_by_id: UnorderedMap::new(StorageKey::ProjectStatsById),
+            donor_contributions_by_project: LookupMap::new(StorageKey::DonorContributionsByProject),
+            public_donations_by_donor_id: LookupMap::new(StorageKey::PublicDonationsByDonorId),
+            donor_ids_by_project_id: LookupMap::new(StorageKey::DonorIdsByProjectId),
             payout_ids_by_project_id: LookupMap::new(StorageKey::PayoutIdsByProjectId),
             payouts_by_id: UnorderedMap::new(StorageKey::PayoutsById),
+            payout_ids: Vector::new(StorageKey::PayoutIds),
+            failed_payout_ids: UnorderedSet::new(StorageKey::FailedPayoutIds),
+            pending_payout_ids: UnorderedSet::new(StorageKey::PendingPayoutIds),
             payouts_challenges: UnorderedMap::new(StorageKey::PayoutsChallenges),
 
             // other
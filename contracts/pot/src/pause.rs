@@ -0,0 +1,49 @@
+use crate::*;
+
+/// Features of the Pot that can be paused independently by owner/admin.
+///
+/// Withdrawal-type methods are deliberately never paused, so that funds that already belong to an account can't be trapped by a pause: `storage_withdraw`, `claim_payout` (payouts that have already been set & survived the cooldown), `withdraw_vested`, `unapply`, `remove_payouts_challenge`, `claim_matching_pool_refunds`, `claim_escrowed_donation_refunds` & `admin_process_escrowed_donation_refunds`.
+/// Donations that are already in flight (i.e. awaiting sybil checks or protocol config) when `Donations` is paused are returned to the sender rather than recorded.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PausableFeature {
+    /// Public round & matching pool donations
+    Donations,
+    /// Applying to the round, and reviewing applications
+    Applications,
+    /// Setting, challenging, processing & sweeping payouts, and updating payout recipients
+    Payouts,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[payable]
+    pub fn admin_pause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.insert(feature);
+        }
+        log_pause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_unpause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.remove(feature);
+        }
+        log_unpause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage);
+    }
+
+    pub fn get_paused_features(&self) -> Vec<PausableFeature> {
+        self.paused_features.to_vec()
+    }
+
+    pub fn is_paused(&self, feature: PausableFeature) -> bool {
+        self.paused_features.contains(&feature)
+    }
+}
@@ -3,7 +3,8 @@ use crate::*;
 // PAYOUTS
 
 pub const PAYOUT_ID_DELIMITER: &str = ":";
-pub type PayoutId = String; // concatenation of application_id + PAYOUT_ID_DELIMITER + incrementing integer per-project
+pub type PayoutId = String; // concatenation of application_id + PAYOUT_ID_DELIMITER + incrementing integer per-Pot (or ESCROWED_DONATIONS_PAYOUT_ID_SUFFIX)
+pub const ESCROWED_DONATIONS_PAYOUT_ID_SUFFIX: &str = "donations";
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
@@ -14,8 +15,16 @@ pub struct Payout {
     pub project_id: ProjectId,
     /// Amount to be paid out
     pub amount: u128,
-    /// Timestamp when the payout was made. None if not yet paid out.
+    /// Timestamp when the payout was made (or its vesting stream started). None if not yet paid out.
     pub paid_at: Option<TimestampMs>,
+    /// Milestone that must be completed before this payout (tranche) is released. None if payout is not tied to a milestone.
+    pub milestone: Option<PayoutMilestone>,
+    /// Timestamp when the (unreleased) payout was clawed back (or swept back into the matching pool after the claim deadline). None if not clawed back.
+    pub clawed_back_at: Option<TimestampMs>,
+    /// Whether this payout releases the project's escrowed public donations (rather than matching pool funds)
+    pub escrowed_donations: bool,
+    /// Vesting stream the payout is withdrawn from (if the Pot has a vesting schedule). None until released, or if payout is transferred in full.
+    pub vesting: Option<PayoutVesting>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -39,6 +48,26 @@ pub struct PayoutInput {
     pub project_id: ProjectId,
 }
 
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutMilestone {
+    /// Description of the deliverable(s) for this milestone
+    pub description: String,
+    /// Timestamp by which the milestone is expected to be completed
+    pub due_at: TimestampMs,
+    /// Timestamp when the milestone was marked complete by chef/admin. None if not yet completed.
+    pub completed_at: Option<TimestampMs>,
+}
+
+/// Ephemeral-only; used for splitting a project's payout into milestone tranches
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutMilestoneInput {
+    pub description: String,
+    pub amount: U128,
+    pub due_at: TimestampMs,
+}
+
 /// Ephemeral-only
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
@@ -49,8 +78,16 @@ pub struct PayoutExternal {
     pub project_id: ProjectId,
     /// Amount to be paid out
     pub amount: U128,
-    /// Timestamp when the payout was made. None if not yet paid out.
+    /// Timestamp when the payout was made (or its vesting stream started). None if not yet paid out.
     pub paid_at: Option<TimestampMs>,
+    /// Milestone that must be completed before this payout (tranche) is released. None if payout is not tied to a milestone.
+    pub milestone: Option<PayoutMilestone>,
+    /// Timestamp when the (unreleased) payout was clawed back (or swept back into the matching pool after the claim deadline). None if not clawed back.
+    pub clawed_back_at: Option<TimestampMs>,
+    /// Whether this payout releases the project's escrowed public donations (rather than matching pool funds)
+    pub escrowed_donations: bool,
+    /// Vesting stream the payout is withdrawn from (if the Pot has a vesting schedule). None until released, or if payout is transferred in full.
+    pub vesting: Option<PayoutVesting>,
 }
 
 impl Payout {
@@ -60,8 +97,22 @@ impl Payout {
             project_id: self.project_id.clone(),
             amount: U128(self.amount),
             paid_at: self.paid_at,
+            milestone: self.milestone.clone(),
+            clawed_back_at: self.clawed_back_at,
+            escrowed_donations: self.escrowed_donations,
+            vesting: self.vesting.clone(),
         }
     }
+
+    /// Whether the payout can be transferred to the project (i.e. not yet paid, not clawed back, and any milestone has been completed)
+    pub fn is_releasable(&self) -> bool {
+        self.paid_at.is_none()
+            && self.clawed_back_at.is_none()
+            && self
+                .milestone
+                .as_ref()
+                .is_none_or(|milestone| milestone.completed_at.is_some())
+    }
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
@@ -75,6 +126,19 @@ pub struct PayoutsChallenge {
     pub admin_notes: Option<String>,
     /// Whether the challenge has been resolved
     pub resolved: bool,
+    /// Bond (in NEAR) attached by the challenger
+    pub bond: Balance,
+    /// Outcome of the challenge, set by admin/owner on resolution
+    pub outcome: Option<PayoutsChallengeOutcome>,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PayoutsChallengeOutcome {
+    /// Challenge is valid; bond is refunded & payouts no longer take effect, so that they can be cleared & set again
+    Upheld,
+    /// Challenge is frivolous; bond is forfeited to the matching pool (or, for FT Pots, to the owner)
+    Dismissed,
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -104,6 +168,10 @@ pub struct PayoutsChallengeExternal {
     pub admin_notes: Option<String>,
     /// Whether the challenge has been resolved
     pub resolved: bool,
+    /// Bond (in NEAR) attached by the challenger
+    pub bond: U128,
+    /// Outcome of the challenge, set by admin/owner on resolution
+    pub outcome: Option<PayoutsChallengeOutcome>,
 }
 
 impl PayoutsChallenge {
@@ -114,82 +182,603 @@ impl PayoutsChallenge {
             reason: self.reason.clone(),
             admin_notes: self.admin_notes.clone(),
             resolved: self.resolved,
+            bond: U128(self.bond),
+            outcome: self.outcome.clone(),
+        }
+    }
+}
+
+/// Ephemeral-only; result of on-chain quadratic funding calculation for a single project
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct QfPayoutCalculation {
+    /// ID of the approved project
+    pub project_id: ProjectId,
+    /// Number of unique public round donors to the project
+    pub unique_donors: u32,
+    /// Sum of net amounts of public round donations to the project
+    pub total_donations: U128,
+    /// Pairwise-bounded CLR matching amount, i.e. the sum over each pair of the project's donors of `sqrt(contribution * other_contribution) * pairwise_match_threshold / (sum over all projects of the pair's sqrt(contribution * other_contribution))`. Zero for owner, admins & chef.
+    pub qf_score: U128,
+    /// Share of matching pool allocated to the project
+    pub matching_amount: U128,
+}
+
+/// Ephemeral-only; used when manually adjusting payouts away from the on-chain calculation
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutAdjustment {
+    pub project_id: ProjectId,
+    /// Amount as calculated on-chain. None if the calculation has not been completed.
+    pub calculated_amount: Option<U128>,
+    /// Amount set by chef/admin
+    pub amount: U128,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum QfCalculationStage {
+    /// Removing intermediate results left in storage by a previous (restarted) calculation
+    Clearing,
+    /// Summing, for each pair of donors, `sqrt(contribution * other_contribution)` over every project they have both donated to
+    PairTotals,
+    /// Summing each project's pairwise matches, each bounded by `pairwise_match_threshold` in proportion to the pair's total
+    ProjectScores,
+    /// Summing the scores of the projects eligible for the matching pool & for each earmarked sub-pool
+    ScoreTotals,
+    /// Allocating the matching pool (& its earmarked sub-pools) to projects according to their scores
+    Allocation,
+    /// Results can be viewed via `get_qf_payout_calculations` & set via `chef_set_payouts_from_calculation`
+    Complete,
+}
+
+/// Progress of the on-chain quadratic funding calculation, which is processed in batches via `chef_calculate_qf_payouts`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct QfCalculation {
+    /// Incremented whenever the calculation is restarted (e.g. a project is approved or disqualified)
+    pub epoch: u64,
+    pub stage: QfCalculationStage,
+    /// Index (into approved projects) of the project processed next
+    pub project_index: u64,
+    /// Indices (into the project's donors) of the pair of donors processed next
+    pub donor_index: u64,
+    pub other_donor_index: u64,
+    /// Sum of the scores of all approved projects (set in the `ScoreTotals` stage)
+    pub total_qf_score: U128,
+    /// Project with the highest score, which receives the rounding dust of the (general) matching pool allocation
+    pub top_project_id: Option<ProjectId>,
+    /// Amount of the (general) matching pool allocated so far (in the `Allocation` stage)
+    pub allocated_amount: U128,
+}
+
+impl QfCalculation {
+    pub fn new(epoch: u64) -> Self {
+        Self {
+            epoch,
+            stage: QfCalculationStage::Clearing,
+            project_index: 0,
+            donor_index: 0,
+            other_donor_index: 1,
+            total_qf_score: U128(0),
+            top_project_id: None,
+            allocated_amount: U128(0),
+        }
+    }
+
+    /// Moves on to the first pair of donors of the next project
+    fn next_project(&mut self) {
+        self.project_index += 1;
+        self.donor_index = 0;
+        self.other_donor_index = 1;
+    }
+
+    /// Moves on to the next pair of donors to a project with `donors_count` donors (or to the next project, once all of its pairs have been processed)
+    fn next_pair(&mut self, donors_count: u64) {
+        self.other_donor_index += 1;
+        if self.other_donor_index >= donors_count {
+            self.donor_index += 1;
+            self.other_donor_index = self.donor_index + 1;
+        }
+        if self.other_donor_index >= donors_count {
+            self.next_project();
+        }
+    }
+
+    /// Moves on to the next stage, starting again from the first project
+    fn next_stage(&mut self) {
+        let stage = match self.stage {
+            QfCalculationStage::Clearing => QfCalculationStage::PairTotals,
+            QfCalculationStage::PairTotals => QfCalculationStage::ProjectScores,
+            QfCalculationStage::ProjectScores => QfCalculationStage::ScoreTotals,
+            QfCalculationStage::ScoreTotals => QfCalculationStage::Allocation,
+            _ => QfCalculationStage::Complete,
+        };
+        self.start_stage(stage);
+    }
+
+    fn start_stage(&mut self, stage: QfCalculationStage) {
+        if stage == QfCalculationStage::ScoreTotals {
+            self.total_qf_score = U128(0);
+            self.top_project_id = None;
+        }
+        if stage == QfCalculationStage::Allocation {
+            self.allocated_amount = U128(0);
+        }
+        self.stage = stage;
+        self.project_index = 0;
+        self.donor_index = 0;
+        self.other_donor_index = 1;
+    }
+}
+
+/// Running sum of `sqrt(contribution * other_contribution)` for a pair of donors, over all projects they have both donated to
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct QfPairTotal {
+    pub total: u128,
+    /// Number of projects whose scores still need this total (it is removed once all of them have been scored)
+    pub projects_remaining: u32,
+}
+
+/// Running totals used to allocate an earmarked sub-pool among its eligible projects, pro-rata by score (or equally, if none of them have a score)
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct QfSubPoolAllocation {
+    /// Number of approved projects eligible for the sub-pool
+    pub eligible_projects_count: u32,
+    /// Sum of the scores of the eligible projects
+    pub total_qf_score: u128,
+    /// Eligible project with the highest score (or the first eligible project, if none of them have a score), which receives the rounding dust
+    pub top_project_id: ProjectId,
+    /// Amount of the sub-pool allocated so far
+    pub allocated_amount: u128,
+}
+
+/// Payouts that are being set in batches (via `chef_set_payouts` & `chef_finalize_payouts`, or via `chef_set_payouts_from_calculation`), which only take effect (starting the cooldown period) once finalized
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutsDraft {
+    /// Whether payouts are being set from the QF calculation rather than manually
+    pub from_calculation: bool,
+    /// Justification for manual payouts that differ from the calculated amounts
+    pub justification: Option<String>,
+    /// Whether approved projects are being processed (adding escrowed donations & checking earmarked sub-pools), after which no more manual payouts can be added
+    pub finalizing: bool,
+    /// Index (into approved projects) of the project processed next while finalizing
+    pub project_index: u64,
+    /// Sum of the matching fund payouts added so far
+    pub total_amount: U128,
+    /// Matching fund payouts to the projects processed so far, summed by the set (bitmask of sub-pool IDs) of earmarked sub-pools the project is eligible for
+    pub amounts_by_sub_pool_mask: Vec<(u32, U128)>,
+    /// Number of projects processed so far that are eligible for each earmarked sub-pool (by sub-pool ID)
+    pub eligible_projects_counts: Vec<(SubPoolId, u32)>,
+}
+
+impl PayoutsDraft {
+    pub fn new(from_calculation: bool) -> Self {
+        Self {
+            from_calculation,
+            justification: None,
+            // payouts from the calculation are added as approved projects are processed
+            finalizing: from_calculation,
+            project_index: 0,
+            total_amount: U128(0),
+            amounts_by_sub_pool_mask: vec![],
+            eligible_projects_counts: vec![],
         }
     }
 }
 
 #[near_bindgen]
 impl Contract {
-    // set_payouts (callable by chef or admin)
+    /// Sets payouts exactly as calculated on-chain by `chef_calculate_qf_payouts` (callable by chef or admin), processing up to `limit` approved projects (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT) per call & resuming from where the previous call left off. The calculation must be complete. Payouts take effect (& the cooldown period starts) once the last approved project has been processed, in which case None is returned. Below saturation, the unallocated part of the matching pool is left to be swept to the remainder recipient.
     #[payable]
-    pub fn chef_set_payouts(&mut self, payouts: Vec<PayoutInput>) {
+    pub fn chef_set_payouts_from_calculation(
+        &mut self,
+        limit: Option<u64>,
+    ) -> Option<PayoutsDraft> {
+        self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_qf_calculation_complete();
+        let draft = self.get_or_start_payouts_draft(true);
+        self.process_payouts_draft(draft, limit)
+    }
+
+    /// Manually overrides the on-chain calculation (callable by chef or admin), adding a batch of payouts to the payouts draft (a new draft is started if there is none, in which case any existing payouts must first be cleared via `chef_clear_payouts`). Once all batches have been added, payouts take effect via `chef_finalize_payouts`, & must add up to the matching pool balance. Payouts that differ from the calculated amounts (including calculated payouts that are left out, which are logged while finalizing) are logged in `adjust_payouts` events, in which case a justification must be provided. If the calculation has not been completed, every payout counts as an adjustment. A project can only be given one payout.
+    #[payable]
+    pub fn chef_set_payouts(
+        &mut self,
+        payouts: Vec<PayoutInput>,
+        justification: Option<String>,
+    ) -> PayoutsDraft {
         self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        let mut draft = self.get_or_start_payouts_draft(false);
+        assert!(
+            !draft.finalizing || payouts.is_empty(),
+            "Payouts are being finalized; call chef_finalize_payouts (or chef_clear_payouts to start over)"
+        );
+        if let Some(justification) = justification
+            .map(|justification| justification.trim().to_string())
+            .filter(|justification| !justification.is_empty())
+        {
+            draft.justification = Some(justification);
+        }
+        let mut adjustments: Vec<PayoutAdjustment> = vec![];
+        let mut payouts_set: Vec<PayoutExternal> = vec![];
+        for payout in payouts {
+            let calculated_amount = self.get_calculated_payout_amount(&payout.project_id);
+            if calculated_amount != Some(payout.amount.0) {
+                adjustments.push(PayoutAdjustment {
+                    project_id: payout.project_id.clone(),
+                    calculated_amount: calculated_amount.map(U128),
+                    amount: payout.amount,
+                });
+            }
+            payouts_set.push(self.add_draft_payout(
+                &mut draft,
+                &payout.project_id,
+                payout.amount.0,
+            ));
+        }
+        self.log_payout_adjustments(&draft, &adjustments);
+        self.payouts_draft.set(&draft);
+        log_set_payouts_event(&env::predecessor_account_id(), &payouts_set, None);
+        draft
+    }
+
+    /// Finalizes payouts added via `chef_set_payouts` (callable by chef or admin), processing up to `limit` approved projects (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT) per call & resuming from where the previous call left off. Escrowed donations are added as payouts, & calculated payouts that were left out are logged as adjustments. Payouts take effect (& the cooldown period starts) once the last approved project has been processed, in which case None is returned.
+    #[payable]
+    pub fn chef_finalize_payouts(&mut self, limit: Option<u64>) -> Option<PayoutsDraft> {
+        self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        let mut draft = self.get_or_start_payouts_draft(false);
+        draft.finalizing = true;
+        self.process_payouts_draft(draft, limit)
+    }
+
+    /// Payouts that are being set & have not yet been finalized. None if payouts have been finalized (or have not been set).
+    pub fn get_payouts_draft(&self) -> Option<PayoutsDraft> {
+        self.payouts_draft.get()
+    }
+
+    /// Removes up to `limit` payouts (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT), most recently created first, & discards any payouts draft (callable by chef or admin), e.g. before payouts are set again or once a payouts challenge has been upheld. Cleared payouts no longer take effect, so the cooldown period ends. Returns the number of payouts left to clear.
+    #[payable]
+    pub fn chef_clear_payouts(&mut self, limit: Option<u64>) -> u64 {
+        self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_not_cancelled();
+        // verify that payouts have not already been (partially) processed
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Payouts have already been processed"
+        );
+        let limit = limit.unwrap_or(DEFAULT_PAYOUTS_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        self.payouts_draft.remove();
+        // the cooldown period starts again once payouts are set again
+        self.cooldown_end_ms.remove();
+        let mut cleared_count: u64 = 0;
+        while cleared_count < limit {
+            match self.payout_ids.pop() {
+                Some(payout_id) => self.remove_payout(&payout_id),
+                None => break,
+            }
+            cleared_count += 1;
+        }
+        if self.payout_ids.is_empty() {
+            // cleared payouts no longer have amounts reclaimed from them
+            self.reclaimed_payouts_balance = 0;
+            self.payouts_review_required = false;
+        }
+        log!(
+            "Cleared {} payouts ({} left)",
+            cleared_count,
+            self.payout_ids.len()
+        );
+        self.payout_ids.len()
+    }
+
+    /// Continues the payouts draft (if any), or starts a new one once any existing payouts have been cleared
+    fn get_or_start_payouts_draft(&self, from_calculation: bool) -> PayoutsDraft {
+        self.assert_not_cancelled();
         // verify that the round has closed
         self.assert_round_closed();
-        // verify that payouts have not already been processed
+        // verify that payouts have not already been (partially) processed
         assert!(
-            self.all_paid_out == false,
+            !self.is_payouts_processing_started(),
             "Payouts have already been processed"
         );
-        // clear any existing payouts (in case this is a reset, e.g. fixing an error)
-        for application_id in self.approved_application_ids.iter() {
-            // if there are payouts for the project...
-            if let Some(payout_ids_for_application) =
-                self.payout_ids_by_project_id.get(&application_id)
-            {
-                // ...remove them
-                for payout_id in payout_ids_for_application.iter() {
-                    self.payouts_by_id.remove(&payout_id);
+        match self.payouts_draft.get() {
+            Some(draft) => {
+                assert!(
+                    draft.from_calculation == from_calculation,
+                    "{}",
+                    if draft.from_calculation {
+                        "Payouts are being set from the QF calculation; call chef_set_payouts_from_calculation (or chef_clear_payouts to start over)"
+                    } else {
+                        "Payouts are being set manually; call chef_finalize_payouts (or chef_clear_payouts to start over)"
+                    }
+                );
+                draft
+            }
+            None => {
+                assert!(
+                    self.payout_ids.is_empty(),
+                    "Existing payouts must first be cleared via chef_clear_payouts"
+                );
+                PayoutsDraft::new(from_calculation)
+            }
+        }
+    }
+
+    /// Processes up to `limit` approved projects of the payouts draft, adding calculated payouts (if the draft is from the calculation) & escrowed donations, & tallying payouts to projects eligible for earmarked sub-pools. Once the last approved project has been processed, payouts are checked against the matching pool & take effect.
+    fn process_payouts_draft(
+        &mut self,
+        mut draft: PayoutsDraft,
+        limit: Option<u64>,
+    ) -> Option<PayoutsDraft> {
+        let limit = limit.unwrap_or(DEFAULT_PAYOUTS_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        let sub_pools: Vec<(SubPoolId, MatchingSubPool)> = self
+            .matching_sub_pools_by_id
+            .iter()
+            .map(|(sub_pool_id, sub_pool)| (sub_pool_id, MatchingSubPool::from(sub_pool)))
+            .filter(|(_sub_pool_id, sub_pool)| sub_pool.balance > 0)
+            .collect();
+        let total_projects = self.approved_application_ids.len();
+        let start_index = draft.project_index;
+        let end_index = std::cmp::min(start_index.saturating_add(limit), total_projects);
+        let mut adjustments: Vec<PayoutAdjustment> = vec![];
+        let mut payouts_set: Vec<PayoutExternal> = vec![];
+        for index in start_index..end_index {
+            let project_id = self
+                .approved_application_ids
+                .as_vector()
+                .get(index)
+                .expect("no project");
+            let mut amount = self.get_project_matching_payouts_amount(&project_id);
+            let calculated_amount = self
+                .get_calculated_payout_amount(&project_id)
+                .unwrap_or_default();
+            if draft.from_calculation {
+                if calculated_amount > 0 {
+                    payouts_set.push(self.add_draft_payout(
+                        &mut draft,
+                        &project_id,
+                        calculated_amount,
+                    ));
+                    amount = Some(calculated_amount);
+                }
+            } else if amount.is_none() && calculated_amount > 0 {
+                // calculated payouts that have been left out are adjusted to zero
+                adjustments.push(PayoutAdjustment {
+                    project_id: project_id.clone(),
+                    calculated_amount: Some(U128(calculated_amount)),
+                    amount: U128(0),
+                });
+            }
+            // escrowed public donations are released to approved projects alongside matching funds
+            if let Some(escrowed_amount) = self.escrowed_donations_by_project_id.get(&project_id) {
+                let payout = Payout {
+                    id: format!(
+                        "{}{}{}",
+                        project_id, PAYOUT_ID_DELIMITER, ESCROWED_DONATIONS_PAYOUT_ID_SUFFIX
+                    ),
+                    amount: escrowed_amount,
+                    project_id: project_id.clone(),
+                    paid_at: None,
+                    milestone: None,
+                    clawed_back_at: None,
+                    escrowed_donations: true,
+                    vesting: None,
+                };
+                self.insert_new_payout(&payout);
+                payouts_set.push(payout.to_external());
+            }
+            // tally payouts by the earmarked sub-pools the project is eligible for
+            let mut mask = 0u32;
+            for (sub_pool_id, sub_pool) in sub_pools.iter() {
+                if self.is_eligible_for_earmark(&project_id, &sub_pool.earmark) {
+                    mask |= 1 << sub_pool_id;
+                    match draft
+                        .eligible_projects_counts
+                        .iter_mut()
+                        .find(|(id, _count)| id == sub_pool_id)
+                    {
+                        Some((_id, count)) => *count += 1,
+                        None => draft.eligible_projects_counts.push((*sub_pool_id, 1)),
+                    }
                 }
-                // ...and remove the set of payout IDs for the project
-                let removed = self.payout_ids_by_project_id.remove(&application_id);
-                if let Some(mut removed) = removed {
-                    removed.clear();
+            }
+            if mask != 0 {
+                let amount = amount.unwrap_or_default();
+                match draft
+                    .amounts_by_sub_pool_mask
+                    .iter_mut()
+                    .find(|(other_mask, _amount)| *other_mask == mask)
+                {
+                    Some((_mask, total)) => total.0 += amount,
+                    None => draft.amounts_by_sub_pool_mask.push((mask, U128(amount))),
                 }
             }
         }
-        // get down to business
-        let mut running_total: u128 = 0;
-        // for each payout:
-        for payout in payouts.iter() {
-            // verify that the project exists and is approved
-            self.assert_approved_application(&payout.project_id);
-            // TODO: check that the project is not owner, admin or chef
-            // add amount to running total
-            running_total += payout.amount.0;
-            // set cooldown_end to now + 1 week (?)
-            self.cooldown_end_ms
-                .set(&(env::block_timestamp_ms() + &self.cooldown_period_ms));
-            // add payout to payouts
-            let mut payout_ids_for_application = self
-                .payout_ids_by_project_id
-                .get(&payout.project_id)
-                .unwrap_or(UnorderedSet::new(StorageKey::PayoutIdsByProjectIdInner {
-                    project_id: payout.project_id.clone(),
-                }));
-            let payout_id = format!(
-                "{}{}{}",
-                payout.project_id,
-                PAYOUT_ID_DELIMITER,
-                payout_ids_for_application.len() + 1
+        draft.project_index = end_index;
+        log!(
+            "Processed approved projects {} to {} of {}",
+            start_index,
+            end_index,
+            total_projects
+        );
+        self.log_payout_adjustments(&draft, &adjustments);
+        if end_index < total_projects {
+            self.payouts_draft.set(&draft);
+            log_set_payouts_event(&env::predecessor_account_id(), &payouts_set, None);
+            return Some(draft);
+        }
+        // error if total is not equal to matching_pool_balance (NB: milestones are set per-project afterwards, and must add up to the project's payout)
+        if draft.from_calculation && self.is_qf_calculation_below_saturation() {
+            assert!(
+                draft.total_amount.0 <= self.matching_pool_balance,
+                "Total payouts cannot exceed matching pool balance"
+            );
+        } else {
+            assert!(
+                draft.total_amount.0 == self.matching_pool_balance,
+                "Total payouts must equal matching pool balance"
             );
-            let payout = Payout {
-                id: payout_id.clone(),
-                amount: payout.amount.0,
-                project_id: payout.project_id.clone(),
-                paid_at: None,
-            };
-            payout_ids_for_application.insert(&payout_id);
-            self.payout_ids_by_project_id
-                .insert(&payout.project_id, &payout_ids_for_application);
-            self.payouts_by_id
-                .insert(&payout_id, &VersionedPayout::Current(payout));
         }
-        // error if running total is not equal to matching_pool_balance (NB: this logic will change once milestones are supported)
+        // earmarked sub-pools must be paid out to their eligible projects
+        self.assert_payouts_respect_matching_sub_pools(&draft);
+        self.payouts_draft.remove();
+        // set cooldown_end to now + 1 week (?)
+        self.cooldown_end_ms
+            .set(&(env::block_timestamp_ms() + &self.cooldown_period_ms));
+        self.payouts_review_required = false;
+        log_set_payouts_event(
+            &env::predecessor_account_id(),
+            &payouts_set,
+            self.cooldown_end_ms.get(),
+        );
+        None
+    }
+
+    /// Adds a matching fund payout to the payouts draft
+    fn add_draft_payout(
+        &mut self,
+        draft: &mut PayoutsDraft,
+        project_id: &ProjectId,
+        amount: Balance,
+    ) -> PayoutExternal {
+        // verify that the project exists and is approved
+        self.assert_approved_application(project_id);
+        // verify that the project is not owner, admin or chef
+        assert!(
+            !self.is_owner_or_admin(Some(project_id)) && !self.is_chef(Some(project_id)),
+            "Owner, admins & chef cannot receive payouts"
+        );
         assert!(
-            running_total == self.matching_pool_balance,
-            "Total payouts must equal matching pool balance"
+            self.payout_ids_by_project_id.get(project_id).is_none(),
+            "Payout has already been set for project {}",
+            project_id
+        );
+        draft.total_amount = U128(
+            draft
+                .total_amount
+                .0
+                .checked_add(amount)
+                .expect("Overflow occurred when calculating total payouts"),
         );
+        let payout = Payout {
+            id: self.next_payout_id(project_id),
+            amount,
+            project_id: project_id.clone(),
+            paid_at: None,
+            milestone: None,
+            clawed_back_at: None,
+            escrowed_donations: false,
+            vesting: None,
+        };
+        self.insert_new_payout(&payout);
+        payout.to_external()
+    }
+
+    /// Logs payouts that differ from the calculated amounts, which require a justification
+    fn log_payout_adjustments(&self, draft: &PayoutsDraft, adjustments: &[PayoutAdjustment]) {
+        if adjustments.is_empty() {
+            return;
+        }
+        let justification = draft
+            .justification
+            .as_ref()
+            .expect("Justification must be provided when overriding calculated payouts");
+        log_adjust_payouts_event(&env::predecessor_account_id(), justification, adjustments);
+    }
+
+    /// Amount calculated on-chain for a project. None if the calculation has not been completed.
+    fn get_calculated_payout_amount(&self, project_id: &ProjectId) -> Option<Balance> {
+        if self.is_qf_calculation_complete() {
+            Some(self.qf_matching_amounts.get(project_id).unwrap_or_default())
+        } else {
+            None
+        }
+    }
+
+    /// Sum of a project's matching fund payouts. None if the project has none.
+    fn get_project_matching_payouts_amount(&self, project_id: &ProjectId) -> Option<Balance> {
+        let payouts: Vec<Payout> = self
+            .payout_ids_by_project_id
+            .get(project_id)?
+            .iter()
+            .map(|payout_id| Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout")))
+            .filter(|payout| !payout.escrowed_donations)
+            .collect();
+        if payouts.is_empty() {
+            None
+        } else {
+            Some(payouts.iter().map(|payout| payout.amount).sum())
+        }
+    }
+
+    /// Stores a new payout, appending its ID to `payout_ids`
+    pub(crate) fn insert_new_payout(&mut self, payout: &Payout) {
+        let mut payout_ids_for_project = self
+            .payout_ids_by_project_id
+            .get(&payout.project_id)
+            .unwrap_or(UnorderedSet::new(StorageKey::PayoutIdsByProjectIdInner {
+                project_id: payout.project_id.clone(),
+            }));
+        payout_ids_for_project.insert(&payout.id);
+        self.payout_ids_by_project_id
+            .insert(&payout.project_id, &payout_ids_for_project);
+        self.payout_ids.push(&payout.id);
+        self.payouts_by_id
+            .insert(&payout.id, &VersionedPayout::Current(payout.clone()));
+    }
+
+    /// Removes a payout (if it has not already been removed, e.g. along with a disqualified project's payouts)
+    fn remove_payout(&mut self, payout_id: &PayoutId) {
+        let payout = match self.payouts_by_id.remove(payout_id) {
+            Some(payout) => Payout::from(payout),
+            None => return,
+        };
+        self.failed_payout_ids.remove(payout_id);
+        if let Some(mut payout_ids_for_project) =
+            self.payout_ids_by_project_id.get(&payout.project_id)
+        {
+            payout_ids_for_project.remove(payout_id);
+            if payout_ids_for_project.is_empty() {
+                self.payout_ids_by_project_id.remove(&payout.project_id);
+            } else {
+                self.payout_ids_by_project_id
+                    .insert(&payout.project_id, &payout_ids_for_project);
+            }
+        }
+    }
+
+    /// Removes a single project's payouts (e.g. when the project is disqualified). Escrowed donations stay in escrow, to be refunded to their donors. The payouts' IDs are skipped in `payout_ids`.
+    pub(crate) fn remove_project_payouts(&mut self, project_id: &ProjectId) {
+        if let Some(mut payout_ids_for_project) = self.payout_ids_by_project_id.remove(project_id) {
+            for payout_id in payout_ids_for_project.iter() {
+                self.payouts_by_id.remove(&payout_id);
+                self.failed_payout_ids.remove(&payout_id);
+            }
+            payout_ids_for_project.clear();
+        }
+    }
+
+    /// Generates a new payout ID for a project. Payout IDs are never reused, so that a payout ID always refers to the same payout.
+    pub(crate) fn next_payout_id(&mut self, project_id: &ProjectId) -> PayoutId {
+        self.next_payout_index += 1;
+        format!(
+            "{}{}{}",
+            project_id, PAYOUT_ID_DELIMITER, self.next_payout_index
+        )
+    }
+
+    /// Records an amount reclaimed from a payout (a clawed back milestone tranche, an unclaimed payout swept after the claim deadline or the unvested remainder of a terminated vesting stream), which is no longer owed to the project
+    pub(crate) fn reclaim_payout_amount(&mut self, payout_id: &PayoutId, amount: Balance) {
+        self.reclaimed_payouts_balance += amount;
+        log!("Reclaimed {} from payout {}", amount, payout_id);
     }
 
     pub fn get_payouts(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<PayoutExternal> {
@@ -208,60 +797,758 @@ impl Contract {
             .collect()
     }
 
+    /// Calculates quadratic funding matching amounts for approved projects from on-chain public round donations, processing up to `limit` steps (defaults to DEFAULT_QF_CALCULATION_BATCH_LIMIT) per call & resuming from where the previous call left off (callable by chef or admin). A step processes a pair of donors (`PairTotals` & `ProjectScores` stages), a project (`ScoreTotals` & `Allocation` stages) or an intermediate result left in storage by a previous calculation (`Clearing` stage). Returns the calculation's progress; once its stage is `Complete`, results can be viewed via `get_qf_payout_calculations`.
+    /// The calculation is restarted if its inputs change (approved projects, contributions or `pairwise_match_threshold`), and its allocation stages are restarted if the matching pool or eligibility for it changes (matching pool donations & forfeited bonds, project categories, owner, admins or chef).
+    #[payable]
+    pub fn chef_calculate_qf_payouts(&mut self, limit: Option<u64>) -> QfCalculation {
+        self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_not_cancelled();
+        self.assert_round_closed();
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Payouts have already been processed"
+        );
+        let limit = limit.unwrap_or(DEFAULT_QF_CALCULATION_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        let initial_storage_usage = env::storage_usage();
+        let mut calculation = self
+            .qf_calculation
+            .get()
+            .unwrap_or_else(|| QfCalculation::new(0));
+        let mut steps_processed: u64 = 0;
+        while steps_processed < limit && calculation.stage != QfCalculationStage::Complete {
+            if calculation.stage == QfCalculationStage::Clearing {
+                if !self.remove_qf_intermediate_result() {
+                    calculation.next_stage();
+                }
+                steps_processed += 1;
+                continue;
+            }
+            let Some(project_id) = self
+                .approved_application_ids
+                .as_vector()
+                .get(calculation.project_index)
+            else {
+                if calculation.stage == QfCalculationStage::Allocation {
+                    self.allocate_qf_rounding_dust(&calculation);
+                }
+                calculation.next_stage();
+                continue;
+            };
+            match calculation.stage {
+                QfCalculationStage::ScoreTotals => {
+                    self.add_qf_score_totals(&mut calculation, &project_id);
+                    calculation.next_project();
+                }
+                QfCalculationStage::Allocation => {
+                    self.allocate_qf_matching_amount(&mut calculation, &project_id);
+                    calculation.next_project();
+                }
+                _ => {
+                    let donor_ids = self.donor_ids_by_project_id.get(&project_id);
+                    let donors_count = donor_ids.as_ref().map_or(0, |donor_ids| donor_ids.len());
+                    // projects with fewer than two donors have no pairs, and so receive no matching
+                    if calculation.other_donor_index >= donors_count {
+                        calculation.next_project();
+                        continue;
+                    }
+                    let donor_ids = donor_ids.unwrap();
+                    let donor_id = donor_ids.get(calculation.donor_index).expect("no donor");
+                    let other_donor_id = donor_ids
+                        .get(calculation.other_donor_index)
+                        .expect("no donor");
+                    self.process_qf_pair(&calculation, &project_id, donor_id, other_donor_id);
+                    calculation.next_pair(donors_count);
+                }
+            }
+            steps_processed += 1;
+        }
+        self.qf_calculation.set(&calculation);
+        refund_deposit(initial_storage_usage);
+        calculation
+    }
+
+    /// Removes one intermediate result left in storage by a previous calculation. Returns false once there are none left.
+    fn remove_qf_intermediate_result(&mut self) -> bool {
+        if let Some(pair_key) = self.qf_pair_totals.keys_as_vector().get(0) {
+            self.qf_pair_totals.remove(&pair_key);
+        } else if let Some(project_id) = self.qf_project_scores.keys_as_vector().get(0) {
+            self.qf_project_scores.remove(&project_id);
+        } else if let Some(project_id) = self.qf_matching_amounts.keys_as_vector().get(0) {
+            self.qf_matching_amounts.remove(&project_id);
+        } else if !self.qf_sub_pool_allocations.is_empty() {
+            // at most MAX_MATCHING_SUB_POOLS
+            self.qf_sub_pool_allocations.clear();
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Adds a pair of donors' overlap on a project to their pair total (`PairTotals` stage), or adds the pair's bounded match to the project's score (`ProjectScores` stage)
+    fn process_qf_pair(
+        &mut self,
+        calculation: &QfCalculation,
+        project_id: &ProjectId,
+        donor_id: AccountId,
+        other_donor_id: AccountId,
+    ) {
+        let contribution = self
+            .donor_contributions_by_project
+            .get(&(project_id.clone(), donor_id.clone()))
+            .unwrap_or_default()
+            .net_amount;
+        let other_contribution = self
+            .donor_contributions_by_project
+            .get(&(project_id.clone(), other_donor_id.clone()))
+            .unwrap_or_default()
+            .net_amount;
+        let overlap = sqrt_of_product(contribution, other_contribution);
+        if overlap == 0 {
+            return;
+        }
+        // pair totals are shared across projects, so key them by the pair in a canonical order
+        let pair_key = if donor_id < other_donor_id {
+            (donor_id, other_donor_id)
+        } else {
+            (other_donor_id, donor_id)
+        };
+        let mut pair_total = self.qf_pair_totals.get(&pair_key).unwrap_or_default();
+        match calculation.stage {
+            QfCalculationStage::PairTotals => {
+                pair_total.total += overlap;
+                pair_total.projects_remaining += 1;
+                self.qf_pair_totals.insert(&pair_key, &pair_total);
+            }
+            QfCalculationStage::ProjectScores => {
+                let score = self.qf_project_scores.get(project_id).unwrap_or_default()
+                    + mul_div(overlap, self.pairwise_match_threshold, pair_total.total);
+                self.qf_project_scores.insert(project_id, &score);
+                pair_total.projects_remaining -= 1;
+                if pair_total.projects_remaining == 0 {
+                    self.qf_pair_totals.remove(&pair_key);
+                } else {
+                    self.qf_pair_totals.insert(&pair_key, &pair_total);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds a project's score to the total score & to the totals of the earmarked sub-pools it is eligible for (`ScoreTotals` stage). Owner, admins & chef are not eligible for payouts, so their scores are zeroed & they don't take a share of the matching pool.
+    fn add_qf_score_totals(&mut self, calculation: &mut QfCalculation, project_id: &ProjectId) {
+        let qf_score = if self.is_owner_or_admin(Some(project_id)) || self.is_chef(Some(project_id))
+        {
+            self.qf_project_scores.insert(project_id, &0);
+            0
+        } else {
+            self.qf_project_scores.get(project_id).unwrap_or_default()
+        };
+        calculation.total_qf_score = U128(
+            calculation
+                .total_qf_score
+                .0
+                .checked_add(qf_score)
+                .expect("Overflow occurred when calculating total QF score"),
+        );
+        let is_top_project = match calculation.top_project_id.as_ref() {
+            Some(top_project_id) => {
+                qf_score
+                    > self
+                        .qf_project_scores
+                        .get(top_project_id)
+                        .unwrap_or_default()
+            }
+            None => true,
+        };
+        if is_top_project {
+            calculation.top_project_id = Some(project_id.clone());
+        }
+        // at most MAX_MATCHING_SUB_POOLS
+        for (sub_pool_id, sub_pool) in self.matching_sub_pools_by_id.to_vec() {
+            let sub_pool = MatchingSubPool::from(sub_pool);
+            if sub_pool.balance == 0 || !self.is_eligible_for_earmark(project_id, &sub_pool.earmark)
+            {
+                continue;
+            }
+            let allocation = match self.qf_sub_pool_allocations.get(&sub_pool_id) {
+                Some(mut allocation) => {
+                    let top_qf_score = self
+                        .qf_project_scores
+                        .get(&allocation.top_project_id)
+                        .unwrap_or_default();
+                    allocation.eligible_projects_count += 1;
+                    allocation.total_qf_score = allocation
+                        .total_qf_score
+                        .checked_add(qf_score)
+                        .expect("Overflow occurred when calculating total QF score");
+                    if qf_score > top_qf_score {
+                        allocation.top_project_id = project_id.clone();
+                    }
+                    allocation
+                }
+                None => QfSubPoolAllocation {
+                    eligible_projects_count: 1,
+                    total_qf_score: qf_score,
+                    top_project_id: project_id.clone(),
+                    allocated_amount: 0,
+                },
+            };
+            self.qf_sub_pool_allocations
+                .insert(&sub_pool_id, &allocation);
+        }
+    }
+
+    /// Allocates a project's share of each earmarked sub-pool it is eligible for & of the rest of the matching pool (`Allocation` stage), rounded down. Sub-pools are split equally if none of their eligible projects have a score. If the total score reaches the (general) matching pool, it is normalized to the matching pool; otherwise the project is matched its score & the rest of the matching pool is left unallocated.
+    fn allocate_qf_matching_amount(
+        &mut self,
+        calculation: &mut QfCalculation,
+        project_id: &ProjectId,
+    ) {
+        let qf_score = self.qf_project_scores.get(project_id).unwrap_or_default();
+        let mut matching_amount: u128 = 0;
+        for (sub_pool_id, mut allocation) in self.qf_sub_pool_allocations.to_vec() {
+            let sub_pool = self.get_qf_sub_pool(sub_pool_id);
+            if !self.is_eligible_for_earmark(project_id, &sub_pool.earmark) {
+                continue;
+            }
+            let amount = if allocation.total_qf_score == 0 {
+                sub_pool.balance / allocation.eligible_projects_count as u128
+            } else {
+                mul_div(sub_pool.balance, qf_score, allocation.total_qf_score)
+            };
+            allocation.allocated_amount += amount;
+            self.qf_sub_pool_allocations
+                .insert(&sub_pool_id, &allocation);
+            matching_amount += amount;
+        }
+        let general_balance = self.get_qf_general_balance();
+        let amount = if calculation.total_qf_score.0 < general_balance {
+            qf_score
+        } else if calculation.total_qf_score.0 == 0 {
+            0
+        } else {
+            mul_div(general_balance, qf_score, calculation.total_qf_score.0)
+        };
+        calculation.allocated_amount = U128(calculation.allocated_amount.0 + amount);
+        self.qf_matching_amounts
+            .insert(project_id, &(matching_amount + amount));
+    }
+
+    /// Allocates the rounding dust of each earmarked sub-pool to its top project, & of the (general) matching pool to the top project if the total score reaches it, so that allocations add up to the balances (end of `Allocation` stage)
+    fn allocate_qf_rounding_dust(&mut self, calculation: &QfCalculation) {
+        for (sub_pool_id, allocation) in self.qf_sub_pool_allocations.to_vec() {
+            let dust = self.get_qf_sub_pool(sub_pool_id).balance - allocation.allocated_amount;
+            self.add_qf_matching_amount(&allocation.top_project_id, dust);
+        }
+        let general_balance = self.get_qf_general_balance();
+        if calculation.total_qf_score.0 >= general_balance {
+            if let Some(top_project_id) = calculation.top_project_id.as_ref() {
+                self.add_qf_matching_amount(
+                    top_project_id,
+                    general_balance - calculation.allocated_amount.0,
+                );
+            }
+        }
+    }
+
+    fn add_qf_matching_amount(&mut self, project_id: &ProjectId, amount: Balance) {
+        let matching_amount = self.qf_matching_amounts.get(project_id).unwrap_or_default();
+        self.qf_matching_amounts
+            .insert(project_id, &(matching_amount + amount));
+    }
+
+    fn get_qf_sub_pool(&self, sub_pool_id: SubPoolId) -> MatchingSubPool {
+        MatchingSubPool::from(
+            self.matching_sub_pools_by_id
+                .get(&sub_pool_id)
+                .expect("Matching sub-pool does not exist"),
+        )
+    }
+
+    /// Matching pool balance less the balances of earmarked sub-pools with eligible projects (sub-pools with no eligible approved projects can't be restricted, & are treated as part of the general matching pool)
+    fn get_qf_general_balance(&self) -> Balance {
+        self.qf_sub_pool_allocations.keys().fold(
+            self.matching_pool_balance,
+            |general_balance, sub_pool_id| {
+                general_balance - self.get_qf_sub_pool(sub_pool_id).balance
+            },
+        )
+    }
+
+    /// Whether the total score of a completed QF calculation falls short of the (general) matching pool, in which case part of the matching pool is left unallocated
+    pub(crate) fn is_qf_calculation_below_saturation(&self) -> bool {
+        self.qf_calculation.get().is_some_and(|calculation| {
+            calculation.stage == QfCalculationStage::Complete
+                && calculation.total_qf_score.0 < self.get_qf_general_balance()
+        })
+    }
+
+    /// Restarts the QF calculation (if one has been started), e.g. because its inputs have changed. Intermediate results of the previous calculation are removed as the calculation resumes.
+    pub(crate) fn reset_qf_calculation(&mut self) {
+        if let Some(calculation) = self.qf_calculation.get() {
+            self.qf_calculation
+                .set(&QfCalculation::new(calculation.epoch + 1));
+        }
+    }
+
+    /// Restarts the allocation stages of the QF calculation (if they have been reached), e.g. because the matching pool or eligibility for it has changed. Project scores are kept.
+    pub(crate) fn reset_qf_allocation(&mut self) {
+        if let Some(mut calculation) = self.qf_calculation.get() {
+            if matches!(
+                calculation.stage,
+                QfCalculationStage::ScoreTotals
+                    | QfCalculationStage::Allocation
+                    | QfCalculationStage::Complete
+            ) {
+                // at most MAX_MATCHING_SUB_POOLS
+                self.qf_sub_pool_allocations.clear();
+                calculation.start_stage(QfCalculationStage::ScoreTotals);
+                self.qf_calculation.set(&calculation);
+            }
+        }
+    }
+
+    pub(crate) fn is_qf_calculation_complete(&self) -> bool {
+        self.qf_calculation
+            .get()
+            .is_some_and(|calculation| calculation.stage == QfCalculationStage::Complete)
+    }
+
+    /// Progress of the QF calculation. None if it has not been started.
+    pub fn get_qf_calculation(&self) -> Option<QfCalculation> {
+        self.qf_calculation.get()
+    }
+
+    /// Quadratic funding matching amounts for approved projects, as calculated by `chef_calculate_qf_payouts` (which must be complete)
+    pub fn get_qf_payout_calculations(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<QfPayoutCalculation> {
+        self.assert_qf_calculation_complete();
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.approved_application_ids.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(usize::MAX as u64);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        self.approved_application_ids
+            .iter()
+            .skip(start_index as usize)
+            .take(limit as usize)
+            .map(|project_id| self.get_qf_payout_calculation(project_id))
+            .collect()
+    }
+
+    fn get_qf_payout_calculation(&self, project_id: ProjectId) -> QfPayoutCalculation {
+        let project_stats = self
+            .project_stats_by_id
+            .get(&project_id)
+            .map(ProjectStats::from)
+            .unwrap_or_default();
+        QfPayoutCalculation {
+            unique_donors: project_stats.unique_donors,
+            total_donations: U128(project_stats.net_donations),
+            qf_score: U128(self.qf_project_scores.get(&project_id).unwrap_or_default()),
+            matching_amount: U128(
+                self.qf_matching_amounts
+                    .get(&project_id)
+                    .unwrap_or_default(),
+            ),
+            project_id,
+        }
+    }
+
+    fn assert_qf_calculation_complete(&self) {
+        assert!(
+            self.is_qf_calculation_complete(),
+            "QF payouts calculation is not complete; call chef_calculate_qf_payouts"
+        );
+    }
+
+    /// Processes up to `limit` payouts (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT) in order of creation, resuming from where the previous batch left off. Milestone tranches that have not been completed are left pending, to be released by `chef_complete_payout_milestone` (or clawed back). `all_paid_out` is set once every payout has been processed & none is pending, in flight or failed (if any are, it is set once they have been settled).
     #[payable]
-    pub fn admin_process_payouts(&mut self) {
+    pub fn admin_process_payouts(&mut self, limit: Option<u64>) {
         self.assert_admin_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_not_cancelled();
+        // verify that payouts are not claimed by projects instead
+        assert!(
+            !self.payouts_claimable,
+            "Payouts must be claimed by projects via claim_payout"
+        );
         // verify that the round has closed
         self.assert_round_closed();
         // verify that payouts have not already been processed
+        assert!(!self.all_paid_out, "Payouts have already been processed");
+        // verify that the cooldown period has passed
+        self.assert_cooldown_period_complete();
+        // verify that any challenges have been resolved
+        self.assert_all_payouts_challenges_resolved();
+        let limit = limit.unwrap_or(DEFAULT_PAYOUTS_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        // get the next batch of payouts, starting from where the previous batch left off
+        let total_payouts = self.payout_ids.len();
+        let start_index = self.payouts_processed_index;
+        let end_index = std::cmp::min(start_index.saturating_add(limit), total_payouts);
+        // pay out each payout in the batch
+        for index in start_index..end_index {
+            let payout_id = self.payout_ids.get(index).expect("no payout ID");
+            // payouts removed since they were set (e.g. replaced by milestone tranches) are skipped
+            let payout = match self.payouts_by_id.get(&payout_id) {
+                Some(payout) => Payout::from(payout),
+                None => continue,
+            };
+            // check that the project is still approved
+            if !self.approved_application_ids.contains(&payout.project_id) {
+                log!(
+                    "Skipping payout {} as project {} is not approved.",
+                    payout.id,
+                    payout.project_id
+                );
+            // check that the project is not owner, admin or chef (escrowed donations are released regardless, as they are not matching funds)
+            } else if !payout.escrowed_donations
+                && (self.is_owner_or_admin(Some(&payout.project_id))
+                    || self.is_chef(Some(&payout.project_id)))
+            {
+                log!("Skipping payout for project {} as it is owner, admin or chef and not eligible for payouts.", payout.project_id);
+            // milestone tranches are only paid out once completed (subsequent completions are released by chef_complete_payout_milestone)
+            } else if payout.is_releasable() {
+                let recipient_id = self.get_payout_recipient(&payout.project_id);
+                self.transfer_payout_internal(&recipient_id, payout);
+            } else if payout.paid_at.is_none() && payout.clawed_back_at.is_none() {
+                self.pending_payout_ids.insert(&payout.id);
+            }
+        }
+        self.payouts_processed_index = end_index;
+        log!(
+            "Processed payouts {} to {} of {}",
+            start_index,
+            end_index,
+            total_payouts
+        );
+        self.update_all_paid_out();
+    }
+
+    /// Sets `all_paid_out` once payouts processing has passed the last payout & no payout is pending (awaiting milestone completion), in flight or failed
+    pub(crate) fn update_all_paid_out(&mut self) {
+        if !self.payouts_claimable
+            && self.payouts_processed_index == self.payout_ids.len()
+            && self.pending_payout_ids.is_empty()
+            && self.failed_payout_ids.is_empty()
+            && self.payout_transfers_in_flight == 0
+        {
+            self.all_paid_out = true;
+        }
+    }
+
+    /// Transfers all of the caller's releasable payouts to the caller (only if payouts are claimable). Can be called once the cooldown period is complete & any challenges have been resolved, until the claim deadline (if any). Escrowed donations can still be claimed after the claim deadline.
+    /// Deliberately not subject to `PausableFeature::Payouts` (see `PausableFeature`).
+    pub fn claim_payout(&mut self) {
         assert!(
-            self.all_paid_out == false,
-            "Payouts have already been processed"
+            self.payouts_claimable,
+            "Payouts are not claimable for this Pot"
         );
+        self.assert_not_cancelled();
+        // verify that the round has closed
+        self.assert_round_closed();
         // verify that the cooldown period has passed
         self.assert_cooldown_period_complete();
         // verify that any challenges have been resolved
         self.assert_all_payouts_challenges_resolved();
-        // pay out each project
-        // for each approved project...
-        // loop through self.approved_application_ids set
-        for project_id in self.approved_application_ids.iter() {
-            // get application
-            let application = Application::from(
-                self.applications_by_id
-                    .get(&project_id)
-                    .expect("no application"),
-            );
-            // check that the project is not owner, admin or chef
-            if self.is_owner_or_admin(Some(&project_id)) || self.is_chef(Some(&project_id)) {
-                log!("Skipping payout for project {} as it is owner, admin or chef and not eligible for payouts.", project_id);
+        let claim_deadline_passed = self.is_claim_deadline_passed();
+        let project_id = env::predecessor_account_id();
+        self.assert_approved_application(&project_id);
+        let is_eligible_for_matching_funds =
+            !self.is_owner_or_admin(Some(&project_id)) && !self.is_chef(Some(&project_id));
+        let payout_ids_for_project = self
+            .payout_ids_by_project_id
+            .get(&project_id)
+            .expect("No payouts for project");
+        // milestone tranches can only be claimed once completed; owner, admin & chef (and anyone, after the claim deadline) can only claim escrowed donations
+        let payouts: Vec<Payout> = payout_ids_for_project
+            .iter()
+            .map(|payout_id| Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout")))
+            .filter(|payout| {
+                payout.is_releasable()
+                    && (payout.escrowed_donations
+                        || (is_eligible_for_matching_funds && !claim_deadline_passed))
+            })
+            .collect();
+        assert!(
+            !payouts.is_empty(),
+            "{}",
+            if claim_deadline_passed {
+                "Claim deadline has passed"
+            } else if !is_eligible_for_matching_funds {
+                "Owner, admin or chef is not eligible for payouts"
             } else {
-                // ...if there are payouts for the project...
-                if let Some(payout_ids_for_project) = self.payout_ids_by_project_id.get(&project_id)
-                {
-                    // TODO: handle milestones (for now just paying out all payouts)
-                    for payout_id in payout_ids_for_project.iter() {
-                        let mut payout =
-                            Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
-                        if payout.paid_at.is_none() {
-                            // ...transfer funds...
-                            Promise::new(application.project_id.clone())
-                                .transfer(payout.amount)
-                                .then(
-                                    Self::ext(env::current_account_id())
-                                        .with_static_gas(XCC_GAS)
-                                        .transfer_payout_callback(payout.clone()),
-                                );
-                            // update payout to indicate that funds transfer has been initiated
-                            payout.paid_at = Some(env::block_timestamp_ms());
-                            self.payouts_by_id
-                                .insert(&payout_id, &VersionedPayout::Current(payout));
-                        }
-                    }
+                "No payouts to claim"
+            }
+        );
+        let recipient_id = self.get_payout_recipient(&project_id);
+        for payout in payouts {
+            // previously failed claims can be claimed again
+            self.failed_payout_ids.remove(&payout.id);
+            self.transfer_payout_internal(&recipient_id, payout);
+        }
+    }
+
+    /// Reclaims unclaimed payouts once the claim deadline has passed (callable by admin or owner); swept amounts are tallied in `reclaimed_payouts_balance` & swept with the remainder. Processes up to `limit` payouts (defaults to DEFAULT_PAYOUTS_BATCH_LIMIT) starting from `from_index` (into payouts in order of creation). Escrowed donations are not swept, as they remain claimable by their projects.
+    #[payable]
+    pub fn admin_sweep_unclaimed_payouts(&mut self, from_index: Option<u64>, limit: Option<u64>) {
+        self.assert_admin_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_not_cancelled();
+        assert!(
+            self.payouts_claimable,
+            "Payouts are not claimable for this Pot"
+        );
+        assert!(
+            self.is_claim_deadline_passed(),
+            "Claim deadline has not passed"
+        );
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.payout_ids.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(DEFAULT_PAYOUTS_BATCH_LIMIT);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        let end_index = std::cmp::min(start_index.saturating_add(limit), self.payout_ids.len());
+        let unclaimed_payouts: Vec<Payout> = (start_index..end_index)
+            .filter_map(|index| {
+                self.payouts_by_id
+                    .get(&self.payout_ids.get(index).expect("no payout ID"))
+            })
+            .map(Payout::from)
+            .filter(|payout| {
+                payout.paid_at.is_none()
+                    && payout.clawed_back_at.is_none()
+                    && !payout.escrowed_donations
+            })
+            .collect();
+        let mut swept_amount: u128 = 0;
+        for mut payout in unclaimed_payouts {
+            payout.clawed_back_at = Some(env::block_timestamp_ms());
+            self.failed_payout_ids.remove(&payout.id);
+            self.reclaim_payout_amount(&payout.id, payout.amount);
+            swept_amount += payout.amount;
+            self.payouts_by_id
+                .insert(&payout.id.clone(), &VersionedPayout::Current(payout));
+        }
+        log!(
+            "Swept unclaimed payouts totalling {} (reclaimed payouts balance: {})",
+            swept_amount,
+            self.reclaimed_payouts_balance
+        );
+    }
+
+    /// Transfers funds for a payout & marks it as paid (reverted in callback if transfer fails). If the Pot has a vesting schedule, matching fund payouts start vesting instead.
+    pub(crate) fn transfer_payout_internal(
+        &mut self,
+        recipient_id: &AccountId,
+        mut payout: Payout,
+    ) {
+        if payout.escrowed_donations {
+            // release whatever is held in escrow for the project, as some donations may have been refunded since payouts were set
+            payout.amount = self
+                .escrowed_donations_by_project_id
+                .remove(&payout.project_id)
+                .unwrap_or_default();
+            if payout.amount == 0 {
+                log!(
+                    "No escrowed donations left to release for project {}",
+                    payout.project_id
+                );
+                payout.clawed_back_at = Some(env::block_timestamp_ms());
+                self.payouts_by_id
+                    .insert(&payout.id.clone(), &VersionedPayout::Current(payout));
+                return;
+            }
+        } else if let Some(schedule) = self.payout_vesting_schedule.get() {
+            // matching funds are withdrawn by the project as they vest
+            self.start_payout_vesting(payout, schedule);
+            return;
+        }
+        self.transfer_funds(recipient_id, payout.amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(XCC_GAS)
+                .transfer_payout_callback(payout.clone(), recipient_id.clone()),
+        );
+        self.payout_transfers_in_flight += 1;
+        // update payout to indicate that funds transfer has been initiated
+        payout.paid_at = Some(env::block_timestamp_ms());
+        self.payouts_by_id
+            .insert(&payout.id.clone(), &VersionedPayout::Current(payout));
+    }
+
+    /// Splits a project's payout into milestone tranches (callable by chef or admin). Milestone amounts must add up to the project's total unpaid payout amount.
+    #[payable]
+    pub fn chef_set_payout_milestones(
+        &mut self,
+        project_id: ProjectId,
+        milestones: Vec<PayoutMilestoneInput>,
+    ) {
+        self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        assert!(
+            !self.is_payouts_processing_started(),
+            "Cannot set milestones once payouts processing has started"
+        );
+        assert!(!milestones.is_empty(), "At least one milestone is required");
+        let initial_storage_usage = env::storage_usage();
+        let mut payout_ids_for_project = self
+            .payout_ids_by_project_id
+            .get(&project_id)
+            .expect("No payouts set for project");
+        // verify that none of the project's payouts have been released or clawed back, and get total amount (escrowed donations are not split into milestones)
+        let mut project_total: u128 = 0;
+        let mut matching_payout_ids: Vec<PayoutId> = vec![];
+        for payout_id in payout_ids_for_project.iter() {
+            let payout = Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
+            if payout.escrowed_donations {
+                continue;
+            }
+            matching_payout_ids.push(payout_id);
+            assert!(
+                payout.paid_at.is_none() && payout.clawed_back_at.is_none(),
+                "Cannot set milestones once a project's payouts have been paid out or clawed back"
+            );
+            project_total += payout.amount;
+        }
+        let milestones_total: u128 = milestones.iter().map(|milestone| milestone.amount.0).sum();
+        assert!(
+            milestones_total == project_total,
+            "Total milestone amounts must equal project's payout amount of {}",
+            project_total
+        );
+        // replace existing payouts with one payout per milestone
+        for payout_id in matching_payout_ids.iter() {
+            self.payouts_by_id.remove(payout_id);
+            payout_ids_for_project.remove(payout_id);
+        }
+        self.payout_ids_by_project_id
+            .insert(&project_id, &payout_ids_for_project);
+        for milestone in milestones {
+            assert!(
+                milestone.amount.0 > 0,
+                "Milestone amount must be greater than 0"
+            );
+            let payout = Payout {
+                id: self.next_payout_id(&project_id),
+                project_id: project_id.clone(),
+                amount: milestone.amount.0,
+                paid_at: None,
+                milestone: Some(PayoutMilestone {
+                    description: milestone.description,
+                    due_at: milestone.due_at,
+                    completed_at: None,
+                }),
+                clawed_back_at: None,
+                escrowed_donations: false,
+                vesting: None,
+            };
+            self.insert_new_payout(&payout);
+        }
+        refund_deposit(initial_storage_usage);
+    }
+
+    /// Marks a payout milestone as complete (callable by chef or admin). If payouts processing has started, the tranche is released immediately; otherwise it will be released when payouts are processed.
+    #[payable]
+    pub fn chef_complete_payout_milestone(&mut self, payout_id: PayoutId) {
+        self.assert_chef_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_not_cancelled();
+        let mut payout = Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
+        assert!(
+            payout.clawed_back_at.is_none(),
+            "Payout has been clawed back"
+        );
+        let milestone = payout
+            .milestone
+            .as_mut()
+            .expect("Payout is not tied to a milestone");
+        assert!(
+            milestone.completed_at.is_none(),
+            "Milestone has already been completed"
+        );
+        milestone.completed_at = Some(env::block_timestamp_ms());
+        if self.is_payouts_processing_started() {
+            self.pending_payout_ids.remove(&payout_id);
+            let recipient_id = self.get_payout_recipient(&payout.project_id);
+            self.transfer_payout_internal(&recipient_id, payout);
+            self.update_all_paid_out();
+        } else {
+            self.payouts_by_id
+                .insert(&payout_id, &VersionedPayout::Current(payout));
+        }
+    }
+
+    /// Claws back an unreleased milestone tranche (callable by admin or owner). If `redirect_to_project_id` is provided, the amount is redirected to that (approved) project as a new payout; otherwise it is reclaimed (tallied in `reclaimed_payouts_balance`) & swept with the remainder.
+    #[payable]
+    pub fn admin_clawback_payout_milestone(
+        &mut self,
+        payout_id: PayoutId,
+        redirect_to_project_id: Option<ProjectId>,
+    ) {
+        self.assert_admin_or_greater();
+        self.assert_not_cancelled();
+        let initial_storage_usage = env::storage_usage();
+        let mut payout = Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
+        assert!(
+            payout.milestone.is_some(),
+            "Payout is not tied to a milestone"
+        );
+        assert!(payout.paid_at.is_none(), "Payout has already been paid out");
+        assert!(
+            payout.clawed_back_at.is_none(),
+            "Payout has already been clawed back"
+        );
+        payout.clawed_back_at = Some(env::block_timestamp_ms());
+        // a failed tranche transfer can be clawed back instead of retried
+        self.failed_payout_ids.remove(&payout_id);
+        let amount = payout.amount;
+        self.payouts_by_id
+            .insert(&payout_id, &VersionedPayout::Current(payout));
+        if let Some(redirect_to_project_id) = redirect_to_project_id {
+            // verify that the project exists and is approved
+            self.assert_approved_application(&redirect_to_project_id);
+            assert!(
+                !self.is_owner_or_admin(Some(&redirect_to_project_id))
+                    && !self.is_chef(Some(&redirect_to_project_id)),
+                "Cannot redirect payout to owner, admin or chef"
+            );
+            let redirected_payout = Payout {
+                id: self.next_payout_id(&redirect_to_project_id),
+                project_id: redirect_to_project_id.clone(),
+                amount,
+                paid_at: None,
+                milestone: None,
+                clawed_back_at: None,
+                escrowed_donations: false,
+                vesting: None,
+            };
+            self.insert_new_payout(&redirected_payout);
+            if self.is_payouts_processing_started() {
+                // payouts processing has already started, so release immediately (moving the cursor past the redirected payout if it had already reached the end)
+                if self.payouts_processed_index + 1 == self.payout_ids.len() {
+                    self.payouts_processed_index += 1;
                 }
+                let recipient_id = self.get_payout_recipient(&redirect_to_project_id);
+                self.transfer_payout_internal(&recipient_id, redirected_payout);
             }
+        } else {
+            self.reclaim_payout_amount(&payout_id, amount);
+        }
+        if self.is_payouts_processing_started() {
+            self.pending_payout_ids.remove(&payout_id);
+            self.update_all_paid_out();
         }
-        self.all_paid_out = true;
+        refund_deposit(initial_storage_usage);
     }
 
     /// Verifies whether payout transfer completed successfully & updates payout record accordingly
@@ -269,6 +1556,7 @@ impl Contract {
     pub fn transfer_payout_callback(
         &mut self,
         mut payout: Payout,
+        recipient_id: AccountId,
         #[callback_result] call_result: Result<(), PromiseError>,
     ) {
         if call_result.is_err() {
@@ -276,8 +1564,14 @@ impl Contract {
                 "Error paying out amount {:#?} to project {}",
                 payout.amount, payout.project_id
             ));
-            // update payout to indicate error transferring funds
+            // update payout to indicate error transferring funds, & add to failed payouts so that it can be retried
             payout.paid_at = None;
+            if payout.escrowed_donations {
+                // return funds to escrow
+                self.add_escrowed_donations(&payout.project_id, payout.amount);
+            }
+            self.failed_payout_ids.insert(&payout.id);
+            log_payout_transfer_event(&payout.to_external(), &recipient_id, false);
             self.payouts_by_id
                 .insert(&payout.id.clone(), &VersionedPayout::Current(payout));
         } else {
@@ -285,34 +1579,342 @@ impl Contract {
                 "Successfully paid out amount {:#?} to project {}",
                 payout.amount, payout.project_id
             ));
+            log_payout_transfer_event(&payout.to_external(), &recipient_id, true);
+        }
+        self.payout_transfers_in_flight = self.payout_transfers_in_flight.saturating_sub(1);
+        self.update_all_paid_out();
+    }
+
+    /// Retries a failed payout transfer (callable by admin or owner), optionally redirecting it to a different account (e.g. a new payout address supplied by the project). Defaults to the project's payout recipient.
+    #[payable]
+    pub fn admin_retry_failed_payout(
+        &mut self,
+        payout_id: PayoutId,
+        recipient_id: Option<AccountId>,
+    ) {
+        self.assert_admin_or_greater();
+        self.assert_not_paused(PausableFeature::Payouts);
+        self.assert_not_cancelled();
+        assert!(
+            self.failed_payout_ids.remove(&payout_id),
+            "Payout {} is not a failed payout",
+            payout_id
+        );
+        let payout = Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
+        let recipient_id =
+            recipient_id.unwrap_or_else(|| self.get_payout_recipient(&payout.project_id));
+        log!(
+            "Retrying failed payout {} of amount {:#?} to {}",
+            payout_id,
+            payout.amount,
+            recipient_id
+        );
+        self.transfer_payout_internal(&recipient_id, payout);
+        self.update_all_paid_out();
+    }
+
+    pub fn get_failed_payouts(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<PayoutExternal> {
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.failed_payout_ids.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(usize::MAX as u64);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        self.failed_payout_ids
+            .iter()
+            .skip(start_index as usize)
+            .take(limit as usize)
+            .map(|payout_id| {
+                Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout")).to_external()
+            })
+            .collect()
+    }
+
+    /// Returns counts of (pending, succeeded, failed) payouts. Clawed-back payouts are excluded; payouts whose transfers are in flight are counted as succeeded.
+    pub(crate) fn get_payouts_counts(&self) -> (u32, u32, u32) {
+        let mut pending: u32 = 0;
+        let mut succeeded: u32 = 0;
+        for (payout_id, versioned_payout) in self.payouts_by_id.iter() {
+            let payout = Payout::from(versioned_payout);
+            if payout.clawed_back_at.is_some() || self.failed_payout_ids.contains(&payout_id) {
+                continue;
+            }
+            if payout.paid_at.is_some() {
+                succeeded += 1;
+            } else {
+                pending += 1;
+            }
+        }
+        (pending, succeeded, self.failed_payout_ids.len() as u32)
+    }
+
+    /// Sweeps the leftover Pot balance (less storage reserve & any outstanding payouts) to `remainder_recipient` once payouts are complete (callable by admin or owner). If `rollover` is true, `remainder_recipient` must be another Pot deployed by the same factory, and the leftover balance is donated to its matching pool. For FT Pots, only the FT balance is swept; any NEAR left in the Pot (e.g. returned by failed transfers of forfeited bonds or of `near` donations) is never swept, and remains in the Pot account.
+    #[payable]
+    pub fn admin_sweep_remainder(&mut self, rollover: Option<bool>) -> Promise {
+        self.assert_admin_or_greater();
+        self.assert_not_cancelled();
+        assert!(
+            self.all_paid_out || (self.payouts_claimable && self.is_claim_deadline_passed()),
+            "Payouts have not been completed"
+        );
+        let recipient_id = self
+            .remainder_recipient
+            .get()
+            .expect("Remainder recipient is not set");
+        let rollover = rollover.unwrap_or(false);
+        if rollover {
+            self.assert_valid_rollover_pot(&recipient_id);
+        }
+        let outstanding_payouts_amount = self.get_outstanding_payouts_amount();
+        if self.is_base_currency_near() {
+            // retain enough to cover storage, storage deposits held on behalf of donors, bonds of unresolved payouts challenges & payouts that are still owed to projects
+            let reserved_amount = env::storage_byte_cost() * Balance::from(env::storage_usage())
+                + self.storage_deposits.values().sum::<u128>()
+                + self.get_unresolved_challenge_bonds_amount()
+                + outstanding_payouts_amount;
+            let amount = env::account_balance().saturating_sub(reserved_amount);
+            assert!(amount > 0, "No remainder to sweep");
+            self.transfer_remainder(recipient_id, amount, rollover)
+        } else {
+            let ft_balance_of_args = json!({ "account_id": env::current_account_id() })
+                .to_string()
+                .into_bytes();
+            Promise::new(self.base_currency.clone())
+                .function_call("ft_balance_of".to_string(), ft_balance_of_args, 0, XCC_GAS)
+                .then(
+                    Self::ext(env::current_account_id())
+                        .with_static_gas(SWEEP_REMAINDER_CALLBACK_GAS)
+                        .ft_balance_of_for_sweep_callback(
+                            recipient_id,
+                            U128(outstanding_payouts_amount),
+                            rollover,
+                        ),
+                )
+        }
+    }
+
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn ft_balance_of_for_sweep_callback(
+        &mut self,
+        recipient_id: AccountId,
+        outstanding_payouts_amount: U128,
+        rollover: bool,
+        #[callback_result] call_result: Result<U128, PromiseError>,
+    ) -> Promise {
+        let ft_balance = call_result.expect("Failed to get FT balance").0;
+        let amount = ft_balance.saturating_sub(outstanding_payouts_amount.0);
+        assert!(amount > 0, "No remainder to sweep");
+        self.transfer_remainder(recipient_id, amount, rollover)
+    }
+
+    pub(crate) fn transfer_remainder(
+        &self,
+        recipient_id: AccountId,
+        amount: Balance,
+        rollover: bool,
+    ) -> Promise {
+        let callback = Self::ext(env::current_account_id()).with_static_gas(XCC_GAS);
+        if !rollover {
+            return self
+                .transfer_funds(&recipient_id, amount)
+                .then(callback.sweep_remainder_callback(recipient_id, U128(amount)));
+        }
+        let message = format!("Rollover from {}", env::current_account_id());
+        if self.is_base_currency_near() {
+            let donate_args = json!({ "matching_pool": true, "message": message })
+                .to_string()
+                .into_bytes();
+            Promise::new(recipient_id.clone())
+                .function_call("donate".to_string(), donate_args, amount, ROLLOVER_GAS)
+                .then(callback.rollover_remainder_callback(recipient_id, U128(amount)))
+        } else {
+            let msg = json!({ "matching_pool": true, "message": message }).to_string();
+            let ft_transfer_call_args = json!({
+                "receiver_id": recipient_id,
+                "amount": U128(amount),
+                "msg": msg,
+            })
+            .to_string()
+            .into_bytes();
+            Promise::new(self.base_currency.clone())
+                .function_call(
+                    "ft_transfer_call".to_string(),
+                    ft_transfer_call_args,
+                    ONE_YOCTO,
+                    ROLLOVER_GAS,
+                )
+                .then(callback.ft_rollover_remainder_callback(recipient_id, U128(amount)))
+        }
+    }
+
+    /// Records the swept amount & emits `sweep_remainder` event if the remainder was transferred successfully (a failed transfer leaves the remainder in the Pot)
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn sweep_remainder_callback(
+        &mut self,
+        recipient_id: AccountId,
+        amount: U128,
+        #[callback_result] call_result: Result<(), PromiseError>,
+    ) {
+        let swept_amount = if call_result.is_ok() { amount.0 } else { 0 };
+        self.record_swept_remainder(&recipient_id, amount.0, swept_amount, false);
+    }
+
+    /// Records the rolled over amount & emits `sweep_remainder` event if the recipient Pot recorded the matching pool donation. If the donation was rejected (`donate` resolves to `null`) or failed, the deposit is returned to this Pot.
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn rollover_remainder_callback(
+        &mut self,
+        recipient_id: AccountId,
+        amount: U128,
+        #[callback_result] call_result: Result<Option<DonationExternal>, PromiseError>,
+    ) {
+        let swept_amount = match call_result {
+            Ok(Some(_donation)) => amount.0,
+            _ => 0,
+        };
+        self.record_swept_remainder(&recipient_id, amount.0, swept_amount, true);
+    }
+
+    /// Records the rolled over amount & emits `sweep_remainder` event for whatever the recipient Pot used. `ft_transfer_call` resolves to the amount used by the receiver (any unused amount is refunded to this Pot).
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn ft_rollover_remainder_callback(
+        &mut self,
+        recipient_id: AccountId,
+        amount: U128,
+        #[callback_result] call_result: Result<U128, PromiseError>,
+    ) {
+        let swept_amount = call_result.map(|used_amount| used_amount.0).unwrap_or(0);
+        self.record_swept_remainder(&recipient_id, amount.0, swept_amount, true);
+    }
+
+    pub(crate) fn record_swept_remainder(
+        &mut self,
+        recipient_id: &AccountId,
+        amount: Balance,
+        swept_amount: Balance,
+        rollover: bool,
+    ) {
+        if swept_amount > 0 {
+            self.swept_remainder_amount += swept_amount;
+            log_sweep_remainder_event(recipient_id, swept_amount, rollover);
+        } else {
+            log!(format!(
+                "Error sweeping remainder of amount {:#?} to {}",
+                amount, recipient_id
+            ));
         }
     }
 
+    /// Total amount of payouts that are still owed to projects (i.e. not paid out or clawed back, or not yet withdrawn from vesting streams), plus any public donations still held in escrow
+    pub(crate) fn get_outstanding_payouts_amount(&self) -> u128 {
+        self.payouts_by_id
+            .values()
+            .map(Payout::from)
+            .filter(|payout| {
+                (payout.paid_at.is_none() || payout.vesting.is_some())
+                    && payout.clawed_back_at.is_none()
+                    && !payout.escrowed_donations
+            })
+            .map(|payout| match payout.vesting.as_ref() {
+                // unvested remainder of a terminated stream is no longer owed
+                Some(vesting) => {
+                    payout.get_vested_amount(TimestampMs::MAX) - vesting.withdrawn_amount.0
+                }
+                None => payout.amount,
+            })
+            .sum::<u128>()
+            + self.get_total_escrowed_donations()
+    }
+
+    /// Total bonds (in NEAR) held for payouts challenges that have not yet been resolved
+    pub(crate) fn get_unresolved_challenge_bonds_amount(&self) -> u128 {
+        self.payouts_challenges
+            .values()
+            .map(PayoutsChallenge::from)
+            .filter(|challenge| !challenge.resolved)
+            .map(|challenge| challenge.bond)
+            .sum()
+    }
+
+    /// Rollover recipient must be a sibling Pot, i.e. another subaccount of the factory that deployed this Pot
+    pub(crate) fn assert_valid_rollover_pot(&self, pot_id: &AccountId) {
+        let current_account_id = env::current_account_id();
+        let factory_id = current_account_id
+            .as_str()
+            .split_once('.')
+            .map(|(_, parent)| parent);
+        assert!(
+            pot_id != &current_account_id
+                && factory_id.is_some()
+                && pot_id.as_str().split_once('.').map(|(_, parent)| parent) == factory_id,
+            "Rollover recipient must be another Pot deployed by the same factory"
+        );
+    }
+
+    /// Challenges payouts during the cooldown period. Caller must attach `payouts_challenge_bond` plus storage costs.
     #[payable]
     pub fn challenge_payouts(&mut self, reason: String) {
         // anyone can challenge
+        self.assert_not_paused(PausableFeature::Payouts);
         // verify that cooldown is in process
         self.assert_cooldown_period_in_process();
-        // create challenge & store, charging user for storage
+        let challenger_id = env::predecessor_account_id();
+        // an existing (unresolved) challenge is overwritten, & its bond is carried over
+        let existing_bond =
+            if let Some(versioned_challenge) = self.payouts_challenges.get(&challenger_id) {
+                let existing_challenge = PayoutsChallenge::from(versioned_challenge);
+                assert!(
+                    !existing_challenge.resolved,
+                    "Payout challenge already resolved; cannot be updated"
+                );
+                existing_challenge.bond
+            } else {
+                0
+            };
+        // create challenge & store, charging user for bond & storage
         let initial_storage_usage = env::storage_usage();
         let challenge = PayoutsChallenge {
             created_at: env::block_timestamp_ms(),
             reason,
             admin_notes: None,
             resolved: false,
+            bond: self.payouts_challenge_bond,
+            outcome: None,
         };
         // store challenge (overwriting any existing challenge for this user - only one challenge per user allowed)
         self.payouts_challenges.insert(
-            &env::predecessor_account_id(),
-            &VersionedPayoutsChallenge::Current(challenge),
+            &challenger_id,
+            &VersionedPayoutsChallenge::Current(challenge.clone()),
         );
-        refund_deposit(initial_storage_usage);
+        let storage_cost = if env::storage_usage() > initial_storage_usage {
+            calculate_required_storage_deposit(initial_storage_usage)
+        } else {
+            0
+        };
+        let required_deposit = challenge.bond + storage_cost;
+        let available_deposit = env::attached_deposit() + existing_bond;
+        assert!(
+            available_deposit >= required_deposit,
+            "Must attach {} yoctoNEAR to cover challenge bond & storage",
+            required_deposit.saturating_sub(existing_bond)
+        );
+        let refund = available_deposit - required_deposit;
+        if refund > 0 {
+            Promise::new(challenger_id.clone()).transfer(refund);
+        }
+        log_challenge_created_event(&challenge.to_external(challenger_id));
     }
 
     pub fn remove_payouts_challenge(&mut self) {
-        // verify that cooldown is in process
-        self.assert_cooldown_period_in_process();
-        // if a payout challenge exists for this caller, remove it (if unresolved) & refund for freed storage
+        // verify that cooldown is in process (or Pot has been cancelled, so that bonds are not trapped)
+        if !self.is_cancelled() {
+            self.assert_cooldown_period_in_process();
+        }
+        // if a payout challenge exists for this caller, remove it (if unresolved) & refund bond & freed storage
         if let Some(versioned_challenge) =
             self.payouts_challenges.get(&env::predecessor_account_id())
         {
@@ -321,6 +1923,9 @@ impl Contract {
                 let initial_storage_usage = env::storage_usage();
                 self.payouts_challenges
                     .remove(&env::predecessor_account_id());
+                if challenge.bond > 0 {
+                    Promise::new(env::predecessor_account_id()).transfer(challenge.bond);
+                }
                 refund_deposit(initial_storage_usage);
             } else {
                 panic!("Payout challenge already resolved; cannot be removed");
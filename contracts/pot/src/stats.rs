@@ -0,0 +1,268 @@
+use crate::*;
+
+/// Running aggregates of public round donations to a project (updated as donations are recorded, and as escrowed donations are refunded, so that refunded donations are excluded)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProjectStats {
+    /// Sum of total (gross) amounts of public donations to the project
+    pub total_donations: u128,
+    /// Sum of net amounts (after fees & storage) of public donations to the project
+    pub net_donations: u128,
+    /// Number of public donations to the project
+    pub donations_count: u32,
+    /// Number of unique public donors to the project
+    pub unique_donors: u32,
+    /// Sum of square roots of each donor's (net) contribution to the project, as used in quadratic funding calculations
+    pub sum_of_sqrt_contributions: u128,
+    /// Sum of referrer fees paid out of public donations to the project
+    pub total_referrer_fees: u128,
+    /// Number of public donations to the project that included a referrer
+    pub referred_donations_count: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum VersionedProjectStats {
+    Current(ProjectStats),
+}
+
+impl From<VersionedProjectStats> for ProjectStats {
+    fn from(project_stats: VersionedProjectStats) -> Self {
+        match project_stats {
+            VersionedProjectStats::Current(current) => current,
+        }
+    }
+}
+
+/// A donor's public donations to a given project (denominated in the Pot's base currency)
+#[derive(BorshDeserialize, BorshSerialize, Default)]
+pub struct DonorContribution {
+    /// Sum of total (gross) amounts, as counted towards `max_per_donor_per_project`
+    pub total_amount: u128,
+    /// Sum of net amounts (after fees & storage), as used in quadratic funding calculations
+    pub net_amount: u128,
+}
+
+/// Ephemeral-only (used in views)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProjectStatsExternal {
+    pub project_id: ProjectId,
+    pub total_donations: U128,
+    pub net_donations: U128,
+    pub donations_count: u32,
+    pub unique_donors: u32,
+    pub sum_of_sqrt_contributions: U128,
+    pub total_referrer_fees: U128,
+    pub referred_donations_count: u32,
+}
+
+impl ProjectStats {
+    pub fn to_external(&self, project_id: ProjectId) -> ProjectStatsExternal {
+        ProjectStatsExternal {
+            project_id,
+            total_donations: U128(self.total_donations),
+            net_donations: U128(self.net_donations),
+            donations_count: self.donations_count,
+            unique_donors: self.unique_donors,
+            sum_of_sqrt_contributions: U128(self.sum_of_sqrt_contributions),
+            total_referrer_fees: U128(self.total_referrer_fees),
+            referred_donations_count: self.referred_donations_count,
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_project_stats(&self, project_id: ProjectId) -> ProjectStatsExternal {
+        self.project_stats_by_id
+            .get(&project_id)
+            .map(ProjectStats::from)
+            .unwrap_or_default()
+            .to_external(project_id)
+    }
+
+    pub fn get_all_project_stats(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<ProjectStatsExternal> {
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.project_stats_by_id.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(usize::MAX as u64);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        self.project_stats_by_id
+            .iter()
+            .skip(start_index as usize)
+            .take(limit as usize)
+            .map(|(project_id, project_stats)| {
+                ProjectStats::from(project_stats).to_external(project_id)
+            })
+            .collect()
+    }
+
+    /// Records a public donation in the project's stats & the donor's running totals. The donor's contribution entry is created here (so that its storage is accounted for when the donation is recorded), but net amounts are only added once known, via `add_project_stats_net_amount`.
+    pub(crate) fn add_project_stats_donation(
+        &mut self,
+        project_id: &ProjectId,
+        donation: &Donation,
+    ) {
+        let mut project_stats = self
+            .project_stats_by_id
+            .get(project_id)
+            .map(ProjectStats::from)
+            .unwrap_or_default();
+        // stats are denominated in the Pot's base currency
+        let total_amount = donation.normalize(donation.total_amount);
+        project_stats.total_donations += total_amount;
+        project_stats.donations_count += 1;
+        if let Some(referrer_fee) = donation.referrer_fee {
+            project_stats.total_referrer_fees += donation.normalize(referrer_fee);
+            project_stats.referred_donations_count += 1;
+        }
+        let contribution_key = (project_id.clone(), donation.donor_id.clone());
+        let mut contribution = match self.donor_contributions_by_project.get(&contribution_key) {
+            Some(contribution) => {
+                // donor's previous donations may all have been refunded
+                if contribution.total_amount == 0 {
+                    project_stats.unique_donors += 1;
+                }
+                contribution
+            }
+            None => {
+                project_stats.unique_donors += 1;
+                let mut donor_ids =
+                    self.donor_ids_by_project_id
+                        .get(project_id)
+                        .unwrap_or_else(|| {
+                            Vector::new(StorageKey::DonorIdsByProjectIdInner {
+                                project_id: project_id.clone(),
+                            })
+                        });
+                donor_ids.push(&donation.donor_id);
+                self.donor_ids_by_project_id.insert(project_id, &donor_ids);
+                DonorContribution::default()
+            }
+        };
+        contribution.total_amount += total_amount;
+        self.donor_contributions_by_project
+            .insert(&contribution_key, &contribution);
+        // running total across all projects, so that `max_per_donor` can be checked without iterating over the donor's donations
+        let donor_total = self
+            .public_donations_by_donor_id
+            .get(&donation.donor_id)
+            .unwrap_or_default();
+        self.public_donations_by_donor_id
+            .insert(&donation.donor_id, &(donor_total + total_amount));
+        self.project_stats_by_id
+            .insert(project_id, &VersionedProjectStats::Current(project_stats));
+    }
+
+    /// Removes a refunded public donation from the project's stats & the donor's running totals (reversing `add_project_stats_donation`). The donor's contribution entry is kept (with a total of 0 if all of the donor's donations have been refunded), as the donor remains listed in `donor_ids_by_project_id`.
+    pub(crate) fn subtract_project_stats_donation(
+        &mut self,
+        project_id: &ProjectId,
+        donation: &Donation,
+    ) {
+        let mut project_stats = ProjectStats::from(
+            self.project_stats_by_id
+                .get(project_id)
+                .expect("no project stats"),
+        );
+        let total_amount = donation.normalize(donation.total_amount);
+        project_stats.total_donations = project_stats.total_donations.saturating_sub(total_amount);
+        project_stats.donations_count = project_stats.donations_count.saturating_sub(1);
+        if let Some(referrer_fee) = donation.referrer_fee {
+            project_stats.total_referrer_fees = project_stats
+                .total_referrer_fees
+                .saturating_sub(donation.normalize(referrer_fee));
+            project_stats.referred_donations_count =
+                project_stats.referred_donations_count.saturating_sub(1);
+        }
+        let contribution_key = (project_id.clone(), donation.donor_id.clone());
+        let mut contribution = self
+            .donor_contributions_by_project
+            .get(&contribution_key)
+            .expect("no donor contribution");
+        contribution.total_amount = contribution.total_amount.saturating_sub(total_amount);
+        if contribution.total_amount == 0 {
+            project_stats.unique_donors = project_stats.unique_donors.saturating_sub(1);
+        }
+        self.donor_contributions_by_project
+            .insert(&contribution_key, &contribution);
+        let donor_total = self
+            .public_donations_by_donor_id
+            .get(&donation.donor_id)
+            .unwrap_or_default();
+        self.public_donations_by_donor_id.insert(
+            &donation.donor_id,
+            &donor_total.saturating_sub(total_amount),
+        );
+        self.project_stats_by_id
+            .insert(project_id, &VersionedProjectStats::Current(project_stats));
+    }
+
+    /// Adds the net amount of a public donation to the project's stats, updating the donor's contribution (& its square root)
+    pub(crate) fn add_project_stats_net_amount(
+        &mut self,
+        project_id: &ProjectId,
+        donor_id: &AccountId,
+        net_amount: Balance,
+    ) {
+        let mut project_stats = ProjectStats::from(
+            self.project_stats_by_id
+                .get(project_id)
+                .expect("no project stats"),
+        );
+        let contribution_key = (project_id.clone(), donor_id.clone());
+        let mut contribution = self
+            .donor_contributions_by_project
+            .get(&contribution_key)
+            .unwrap_or_default();
+        let previous_net_amount = contribution.net_amount;
+        contribution.net_amount += net_amount;
+        project_stats.net_donations += net_amount;
+        project_stats.sum_of_sqrt_contributions = project_stats.sum_of_sqrt_contributions
+            - integer_sqrt(previous_net_amount)
+            + integer_sqrt(contribution.net_amount);
+        self.donor_contributions_by_project
+            .insert(&contribution_key, &contribution);
+        self.project_stats_by_id
+            .insert(project_id, &VersionedProjectStats::Current(project_stats));
+        // contributions are an input to the QF calculation
+        self.reset_qf_calculation();
+    }
+
+    /// Subtracts the net amount of a refunded public donation from the project's stats, updating the donor's contribution (& its square root), so that the donation no longer counts towards QF matching
+    pub(crate) fn subtract_project_stats_net_amount(
+        &mut self,
+        project_id: &ProjectId,
+        donor_id: &AccountId,
+        net_amount: Balance,
+    ) {
+        let mut project_stats = ProjectStats::from(
+            self.project_stats_by_id
+                .get(project_id)
+                .expect("no project stats"),
+        );
+        let contribution_key = (project_id.clone(), donor_id.clone());
+        let mut contribution = self
+            .donor_contributions_by_project
+            .get(&contribution_key)
+            .expect("no donor contribution");
+        let previous_net_amount = contribution.net_amount;
+        contribution.net_amount = contribution.net_amount.saturating_sub(net_amount);
+        project_stats.net_donations = project_stats.net_donations.saturating_sub(net_amount);
+        project_stats.sum_of_sqrt_contributions = project_stats.sum_of_sqrt_contributions
+            - integer_sqrt(previous_net_amount)
+            + integer_sqrt(contribution.net_amount);
+        self.donor_contributions_by_project
+            .insert(&contribution_key, &contribution);
+        self.project_stats_by_id
+            .insert(project_id, &VersionedProjectStats::Current(project_stats));
+        // contributions are an input to the QF calculation
+        self.reset_qf_calculation();
+    }
+}
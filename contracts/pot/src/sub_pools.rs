@@ -0,0 +1,257 @@
+use crate::*;
+
+pub type SubPoolId = u64;
+
+/// Restricts which projects an earmarked matching pool donation can be paid out to
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum MatchingPoolEarmark {
+    /// Only the listed projects are eligible
+    Projects(Vec<ProjectId>),
+    /// Only projects assigned to this category (by chef/admin) are eligible
+    Category(String),
+}
+
+impl MatchingPoolEarmark {
+    /// Canonical form, so that equivalent earmarks share a sub-pool
+    pub fn normalize(self) -> Self {
+        match self {
+            MatchingPoolEarmark::Projects(mut project_ids) => {
+                project_ids.sort();
+                project_ids.dedup();
+                MatchingPoolEarmark::Projects(project_ids)
+            }
+            MatchingPoolEarmark::Category(category) => {
+                MatchingPoolEarmark::Category(category.trim().to_string())
+            }
+        }
+    }
+}
+
+/// Earmarked portion of the matching pool, created by the chef (or owner/admin). Matching pool donations with the same earmark share a sub-pool.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MatchingSubPool {
+    pub earmark: MatchingPoolEarmark,
+    /// Sum of net amounts of matching pool donations earmarked for this sub-pool (included in `matching_pool_balance`)
+    pub balance: u128,
+    /// Number of matching pool donations earmarked for this sub-pool
+    pub donations_count: u32,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum VersionedMatchingSubPool {
+    Current(MatchingSubPool),
+}
+
+impl From<VersionedMatchingSubPool> for MatchingSubPool {
+    fn from(sub_pool: VersionedMatchingSubPool) -> Self {
+        match sub_pool {
+            VersionedMatchingSubPool::Current(current) => current,
+        }
+    }
+}
+
+/// Ephemeral-only (used in views)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MatchingSubPoolExternal {
+    pub id: SubPoolId,
+    pub earmark: MatchingPoolEarmark,
+    pub balance: U128,
+    pub donations_count: u32,
+}
+
+impl MatchingSubPool {
+    pub fn to_external(&self, id: SubPoolId) -> MatchingSubPoolExternal {
+        MatchingSubPoolExternal {
+            id,
+            earmark: self.earmark.clone(),
+            balance: U128(self.balance),
+            donations_count: self.donations_count,
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_matching_sub_pool(&self, sub_pool_id: SubPoolId) -> Option<MatchingSubPoolExternal> {
+        self.matching_sub_pools_by_id
+            .get(&sub_pool_id)
+            .map(|sub_pool| MatchingSubPool::from(sub_pool).to_external(sub_pool_id))
+    }
+
+    pub fn get_matching_sub_pools(
+        &self,
+        from_index: Option<u64>,
+        limit: Option<u64>,
+    ) -> Vec<MatchingSubPoolExternal> {
+        let start_index: u64 = from_index.unwrap_or_default();
+        assert!(
+            self.matching_sub_pools_by_id.len() >= start_index,
+            "Out of bounds, please use a smaller from_index."
+        );
+        let limit = limit.unwrap_or(usize::MAX as u64);
+        assert_ne!(limit, 0, "Cannot provide limit of 0.");
+        self.matching_sub_pools_by_id
+            .iter()
+            .skip(start_index as usize)
+            .take(limit as usize)
+            .map(|(sub_pool_id, sub_pool)| MatchingSubPool::from(sub_pool).to_external(sub_pool_id))
+            .collect()
+    }
+
+    /// Creates a matching sub-pool for `earmark` (callable by chef or greater), which matching pool donations may then be earmarked for. Sub-pools can only be created by the chef (or owner/admin), so that donors can't take up the limited sub-pool slots or restrict payouts with earmarks of their own.
+    #[payable]
+    pub fn chef_create_matching_sub_pool(
+        &mut self,
+        earmark: MatchingPoolEarmark,
+    ) -> MatchingSubPoolExternal {
+        self.assert_chef_or_greater();
+        self.assert_not_cancelled();
+        let earmark = earmark.normalize();
+        self.assert_valid_earmark(&earmark);
+        assert!(
+            self.get_matching_sub_pool_id(&earmark).is_none(),
+            "Matching sub-pool already exists for this earmark"
+        );
+        assert!(
+            self.matching_sub_pools_by_id.len() < MAX_MATCHING_SUB_POOLS as u64,
+            "Pot cannot have more than {} matching sub-pools",
+            MAX_MATCHING_SUB_POOLS
+        );
+        let initial_storage_usage = env::storage_usage();
+        let sub_pool_id = self.matching_sub_pools_by_id.len() + 1;
+        let sub_pool = MatchingSubPool {
+            earmark,
+            balance: 0,
+            donations_count: 0,
+        };
+        self.matching_sub_pools_by_id.insert(
+            &sub_pool_id,
+            &VersionedMatchingSubPool::Current(sub_pool.clone()),
+        );
+        let sub_pool = sub_pool.to_external(sub_pool_id);
+        log_create_matching_sub_pool_event(&env::predecessor_account_id(), &sub_pool);
+        refund_deposit(initial_storage_usage);
+        sub_pool
+    }
+
+    pub(crate) fn assert_valid_earmark(&self, earmark: &MatchingPoolEarmark) {
+        match earmark {
+            MatchingPoolEarmark::Projects(project_ids) => {
+                assert!(
+                    !project_ids.is_empty(),
+                    "Earmark must include at least one project"
+                );
+                for project_id in project_ids.iter() {
+                    self.assert_approved_application(project_id);
+                }
+            }
+            MatchingPoolEarmark::Category(category) => {
+                assert_valid_category(category);
+            }
+        }
+    }
+
+    /// Verifies that a matching pool donation's earmark (which must be normalized) matches an existing sub-pool
+    pub(crate) fn assert_matching_sub_pool_exists(&self, earmark: &MatchingPoolEarmark) {
+        assert!(
+            self.get_matching_sub_pool_id(earmark).is_some(),
+            "No matching sub-pool exists for this earmark; sub-pools are created by the chef or owner/admin"
+        );
+    }
+
+    pub(crate) fn get_matching_sub_pool_id(
+        &self,
+        earmark: &MatchingPoolEarmark,
+    ) -> Option<SubPoolId> {
+        self.matching_sub_pools_by_id
+            .iter()
+            .map(|(sub_pool_id, sub_pool)| (sub_pool_id, MatchingSubPool::from(sub_pool)))
+            .find(|(_sub_pool_id, sub_pool)| &sub_pool.earmark == earmark)
+            .map(|(sub_pool_id, _sub_pool)| sub_pool_id)
+    }
+
+    pub(crate) fn add_matching_sub_pool_donation(
+        &mut self,
+        sub_pool_id: SubPoolId,
+        amount: Balance,
+    ) {
+        let mut sub_pool = MatchingSubPool::from(
+            self.matching_sub_pools_by_id
+                .get(&sub_pool_id)
+                .expect("Matching sub-pool does not exist"),
+        );
+        sub_pool.balance = sub_pool
+            .balance
+            .checked_add(amount)
+            .expect("Overflow occurred when calculating sub-pool balance");
+        sub_pool.donations_count += 1;
+        self.matching_sub_pools_by_id
+            .insert(&sub_pool_id, &VersionedMatchingSubPool::Current(sub_pool));
+    }
+
+    /// Whether an approved project can receive funds from a sub-pool with the given earmark (owner, admins & chef are never eligible for payouts)
+    pub(crate) fn is_eligible_for_earmark(
+        &self,
+        project_id: &ProjectId,
+        earmark: &MatchingPoolEarmark,
+    ) -> bool {
+        if self.is_owner_or_admin(Some(project_id)) || self.is_chef(Some(project_id)) {
+            return false;
+        }
+        match earmark {
+            MatchingPoolEarmark::Projects(project_ids) => project_ids.contains(project_id),
+            MatchingPoolEarmark::Category(category) => {
+                self.applications_by_id
+                    .get(project_id)
+                    .map(Application::from)
+                    .and_then(|application| application.category)
+                    .as_ref()
+                    == Some(category)
+            }
+        }
+    }
+
+    /// Verifies that each (restricted) sub-pool's balance can be covered by the finalized payouts to its eligible projects. As a project may be eligible for several sub-pools, this checks that every combination of sub-pools is covered by the payouts to projects eligible for any of them. Sub-pools with no eligible approved projects are left out, as their balance can't be restricted & is treated as part of the general matching pool.
+    pub(crate) fn assert_payouts_respect_matching_sub_pools(&self, draft: &PayoutsDraft) {
+        let sub_pools: Vec<(SubPoolId, Balance)> = self
+            .matching_sub_pools_by_id
+            .iter()
+            .map(|(sub_pool_id, sub_pool)| (sub_pool_id, MatchingSubPool::from(sub_pool).balance))
+            .filter(|(sub_pool_id, balance)| {
+                *balance > 0
+                    && draft
+                        .eligible_projects_counts
+                        .iter()
+                        .any(|(id, count)| id == sub_pool_id && *count > 0)
+            })
+            .collect();
+        for subset in 1u32..(1 << sub_pools.len()) {
+            let mut required: u128 = 0;
+            let mut subset_mask: u32 = 0;
+            let mut sub_pool_ids: Vec<SubPoolId> = vec![];
+            for (index, (sub_pool_id, balance)) in sub_pools.iter().enumerate() {
+                if subset & (1 << index) != 0 {
+                    required += balance;
+                    subset_mask |= 1 << sub_pool_id;
+                    sub_pool_ids.push(*sub_pool_id);
+                }
+            }
+            // payout amounts are grouped by the set (bitmask of sub-pool IDs) of sub-pools the project is eligible for
+            let available: u128 = draft
+                .amounts_by_sub_pool_mask
+                .iter()
+                .filter(|(mask, _amount)| *mask & subset_mask != 0)
+                .map(|(_mask, amount)| amount.0)
+                .sum();
+            assert!(
+                available >= required,
+                "Payouts to projects eligible for matching sub-pool(s) {:?} must total at least {}",
+                sub_pool_ids,
+                required
+            );
+        }
+    }
+}
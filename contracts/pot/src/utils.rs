@@ -45,3 +45,67 @@ pub fn refund_deposit(initial_storage_usage: u64) {
         Promise::new(env::predecessor_account_id()).transfer(refund);
     }
 }
+
+/// Integer square root (rounded down), using Newton's method
+pub fn integer_sqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // initial guess is a power of two that is guaranteed to be >= sqrt(n)
+    let mut x: u128 = 1 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Calculates `a * b / c` (rounded down) using a 256-bit intermediate product, so that large yoctoNEAR amounts can be multiplied without overflow
+pub fn mul_div(a: u128, b: u128, c: u128) -> u128 {
+    assert!(c > 0, "Cannot divide by zero");
+    // compute 256-bit product as (hi, lo) from 64-bit limbs
+    let mask = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & mask);
+    let (b_hi, b_lo) = (b >> 64, b & mask);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let cross = (lo_lo >> 64) + (hi_lo & mask) + (lo_hi & mask);
+    let lo = (cross << 64) | (lo_lo & mask);
+    let hi = a_hi * b_hi + (hi_lo >> 64) + (lo_hi >> 64) + (cross >> 64);
+    // binary long division of (hi, lo) by c
+    let mut quotient: u128 = 0;
+    let mut remainder: u128 = 0;
+    for i in (0..256u32).rev() {
+        let bit = if i >= 128 {
+            (hi >> (i - 128)) & 1
+        } else {
+            (lo >> i) & 1
+        };
+        let carry = remainder >> 127 == 1;
+        remainder = (remainder << 1) | bit;
+        if carry || remainder >= c {
+            remainder = remainder.wrapping_sub(c);
+            assert!(
+                i < 128,
+                "Overflow occurred when calculating {} * {} / {}",
+                a,
+                b,
+                c
+            );
+            quotient |= 1 << i;
+        }
+    }
+    quotient
+}
+
+/// Square root of `a * b` (rounded down). If the product overflows, each factor is scaled up by an even power of two so that its square root keeps 64 bits of precision, & the scaling is removed from the product of the square roots.
+pub fn sqrt_of_product(a: u128, b: u128) -> u128 {
+    if let Some(product) = a.checked_mul(b) {
+        return integer_sqrt(product);
+    }
+    let (a_shift, b_shift) = (a.leading_zeros() / 2, b.leading_zeros() / 2);
+    (integer_sqrt(a << (2 * a_shift)) * integer_sqrt(b << (2 * b_shift))) >> (a_shift + b_shift)
+}
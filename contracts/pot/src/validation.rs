@@ -14,6 +14,83 @@ pub(crate) fn assert_valid_pot_description(description: &str) {
     );
 }
 
+pub(crate) fn assert_valid_category(category: &str) {
+    assert!(!category.trim().is_empty(), "Category cannot be empty");
+    assert!(
+        category.len() <= MAX_CATEGORY_LENGTH,
+        "Category cannot be longer than {} bytes",
+        MAX_CATEGORY_LENGTH
+    );
+}
+
+pub(crate) fn assert_valid_application_schema(application_schema: &[ApplicationQuestion]) {
+    assert!(
+        application_schema.len() <= MAX_APPLICATION_QUESTIONS,
+        "Application schema cannot exceed {} questions",
+        MAX_APPLICATION_QUESTIONS
+    );
+    let mut keys: HashSet<&str> = HashSet::new();
+    for question in application_schema {
+        assert!(!question.key.is_empty(), "Question key cannot be empty");
+        assert!(
+            keys.insert(question.key.as_str()),
+            "Duplicate question key: {}",
+            question.key
+        );
+        assert!(
+            question.prompt.len() <= MAX_APPLICATION_QUESTION_PROMPT_LENGTH,
+            "Prompt for question {} is too long",
+            question.key
+        );
+        if let Some(max_length) = question.max_length {
+            assert!(
+                max_length > 0 && max_length <= MAX_APPLICATION_ANSWER_LENGTH,
+                "Max length for question {} must be between 1 and {}",
+                question.key,
+                MAX_APPLICATION_ANSWER_LENGTH
+            );
+        }
+        if let ApplicationQuestionType::Select { options } = &question.question_type {
+            assert!(
+                !options.is_empty(),
+                "Select question {} must have at least one option",
+                question.key
+            );
+        }
+    }
+}
+
+pub(crate) fn assert_valid_review_quorum(review_quorum: u32, reviewers_count: u64) {
+    assert!(
+        review_quorum > 0 && review_quorum as u64 <= reviewers_count,
+        "Review quorum must be between 1 and the number of reviewers ({})",
+        reviewers_count
+    );
+}
+
+pub(crate) fn assert_valid_donation_limits(donation_limits: &DonationLimits) {
+    for limit in [
+        donation_limits.min_amount,
+        donation_limits.max_amount,
+        donation_limits.max_per_donor_per_project,
+        donation_limits.max_per_donor,
+        donation_limits.max_per_project,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        assert!(limit.0 > 0, "Donation limits must be greater than 0");
+    }
+    if let (Some(min_amount), Some(max_amount)) =
+        (donation_limits.min_amount, donation_limits.max_amount)
+    {
+        assert!(
+            min_amount.0 <= max_amount.0,
+            "Minimum donation amount cannot exceed maximum donation amount"
+        );
+    }
+}
+
 pub(crate) fn assert_valid_max_projects(max_projects: u32) {
     assert!(
         max_projects <= MAX_MAX_PROJECTS,
@@ -50,6 +127,67 @@ pub(crate) fn assert_valid_provider_id(provider_id: &ProviderId) {
     provider_id.validate();
 }
 
+pub(crate) fn assert_valid_custom_sybil_checks_count(count: usize) {
+    assert!(
+        count <= MAX_CUSTOM_SYBIL_CHECKS,
+        "Custom sybil checks cannot exceed {}",
+        MAX_CUSTOM_SYBIL_CHECKS
+    );
+}
+
+pub(crate) fn assert_valid_accepted_currencies(
+    accepted_currencies: &[AccountId],
+    base_currency: &AccountId,
+    price_oracle_provider: Option<&ProviderId>,
+    escrow_public_donations: bool,
+) {
+    if accepted_currencies.is_empty() {
+        return;
+    }
+    assert!(
+        accepted_currencies.len() <= MAX_ACCEPTED_CURRENCIES,
+        "Accepted currencies cannot exceed {}",
+        MAX_ACCEPTED_CURRENCIES
+    );
+    assert!(
+        !accepted_currencies.contains(base_currency),
+        "Accepted currencies cannot include the base currency ({})",
+        base_currency
+    );
+    // donations in additional currencies are normalized into the base currency using the price oracle
+    let price_oracle_provider = price_oracle_provider
+        .expect("A price oracle provider is required to accept additional currencies");
+    assert_valid_provider_id(price_oracle_provider);
+    // escrowed donations are released alongside matching funds, which are paid in the base currency
+    assert!(
+        !escrow_public_donations,
+        "Additional currencies cannot be accepted when public donations are escrowed"
+    );
+}
+
+pub(crate) fn assert_valid_claim_deadline_ms(
+    claim_deadline_ms: TimestampMs,
+    public_round_end_ms: TimestampMs,
+) {
+    assert!(
+        claim_deadline_ms > public_round_end_ms,
+        "Claim deadline must be after public round end"
+    );
+}
+
+pub(crate) fn assert_valid_payout_vesting_schedule(
+    payout_vesting_schedule: &PayoutVestingSchedule,
+) {
+    assert!(
+        payout_vesting_schedule.vesting_period_ms > 0,
+        "Vesting period must be greater than 0"
+    );
+    assert!(
+        payout_vesting_schedule.cliff_period_ms <= payout_vesting_schedule.vesting_period_ms,
+        "Cliff period cannot exceed vesting period"
+    );
+}
+
 pub(crate) fn assert_valid_cooldown_period_ms(cooldown_period_ms: u64) {
     assert!(
         cooldown_period_ms >= MIN_COOLDOWN_PERIOD_MS,
@@ -58,6 +196,13 @@ pub(crate) fn assert_valid_cooldown_period_ms(cooldown_period_ms: u64) {
     );
 }
 
+pub(crate) fn assert_valid_pairwise_match_threshold(pairwise_match_threshold: Balance) {
+    assert!(
+        pairwise_match_threshold > 0,
+        "Pairwise match threshold must be greater than 0"
+    );
+}
+
 #[near_bindgen]
 impl Contract {
     pub(crate) fn assert_valid_timestamps(
@@ -86,6 +231,64 @@ impl Contract {
         );
     }
 
+    /// Validates answers against the Pot's application schema (if any)
+    pub(crate) fn assert_valid_application_answers(&self, answers: &[ApplicationAnswer]) {
+        let application_schema = self.application_schema.get().unwrap_or_default();
+        let mut answered_keys: HashSet<&str> = HashSet::new();
+        for answer in answers {
+            let question = application_schema
+                .iter()
+                .find(|question| question.key == answer.key)
+                .unwrap_or_else(|| {
+                    env::panic_str(&format!("Unknown question key: {}", answer.key))
+                });
+            assert!(
+                answered_keys.insert(answer.key.as_str()),
+                "Duplicate answer for question {}",
+                answer.key
+            );
+            let max_length = question.max_length.unwrap_or(MAX_APPLICATION_ANSWER_LENGTH);
+            assert!(
+                answer.value.len() <= max_length as usize,
+                "Answer to question {} cannot exceed {} characters",
+                answer.key,
+                max_length
+            );
+            if answer.value.is_empty() {
+                continue;
+            }
+            let is_valid_type = match &question.question_type {
+                ApplicationQuestionType::Text => true,
+                ApplicationQuestionType::Number => answer.value.parse::<f64>().is_ok(),
+                ApplicationQuestionType::Boolean => {
+                    answer.value == "true" || answer.value == "false"
+                }
+                ApplicationQuestionType::Url => {
+                    answer.value.starts_with("https://") || answer.value.starts_with("http://")
+                }
+                ApplicationQuestionType::AccountId => answer.value.parse::<AccountId>().is_ok(),
+                ApplicationQuestionType::Select { options } => options.contains(&answer.value),
+            };
+            assert!(
+                is_valid_type,
+                "Answer to question {} is not a valid {:?}",
+                answer.key, question.question_type
+            );
+        }
+        for question in application_schema
+            .iter()
+            .filter(|question| question.required)
+        {
+            assert!(
+                answers
+                    .iter()
+                    .any(|answer| answer.key == question.key && !answer.value.is_empty()),
+                "Answer to question {} is required",
+                question.key
+            );
+        }
+    }
+
     pub(crate) fn assert_valid_pot_args(&self, args: &UpdatePotArgs) {
         if let Some(name) = &args.pot_name {
             assert_valid_pot_name(name);
@@ -119,7 +322,14 @@ impl Contract {
         if let Some(sybil_wrapper_provider) = &args.sybil_wrapper_provider {
             assert_valid_provider_id(sybil_wrapper_provider);
         }
+        if let Some(application_schema) = &args.application_schema {
+            assert_valid_application_schema(application_schema);
+        }
+        if let Some(donation_limits) = &args.donation_limits {
+            assert_valid_donation_limits(donation_limits);
+        }
         if let Some(custom_sybil_checks) = &args.custom_sybil_checks {
+            assert_valid_custom_sybil_checks_count(custom_sybil_checks.len());
             for check in custom_sybil_checks {
                 assert_valid_provider_id(&ProviderId::new(
                     check.contract_id.clone().to_string(),
@@ -0,0 +1,214 @@
+use crate::*;
+
+/// Schedule on which matching fund payouts vest once released (set on the Pot). Periods are relative to the time each payout is released.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutVestingSchedule {
+    /// Period (in ms) after release before anything vests. Once the cliff is reached, the amount vested up to that point becomes withdrawable.
+    pub cliff_period_ms: u64,
+    /// Total period (in ms) over which the payout vests linearly
+    pub vesting_period_ms: u64,
+}
+
+/// Vesting stream of a released payout
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutVesting {
+    /// Timestamp when vesting started (i.e. when the payout was released)
+    pub start_at: TimestampMs,
+    /// Timestamp before which nothing can be withdrawn
+    pub cliff_at: TimestampMs,
+    /// Timestamp when the payout is fully vested
+    pub end_at: TimestampMs,
+    /// Amount withdrawn by the project so far
+    pub withdrawn_amount: U128,
+    /// Timestamp when the stream was terminated by owner/admin (nothing vests after this). None if not terminated.
+    pub terminated_at: Option<TimestampMs>,
+}
+
+impl Payout {
+    /// Amount of the payout that has vested by `timestamp_ms`: nothing before the cliff, then linearly from start to end (stopping at termination, if any). Payouts without a vesting stream are fully vested.
+    pub fn get_vested_amount(&self, timestamp_ms: TimestampMs) -> u128 {
+        let Some(vesting) = self.vesting.as_ref() else {
+            return self.amount;
+        };
+        let timestamp_ms = vesting.terminated_at.map_or(timestamp_ms, |terminated_at| {
+            std::cmp::min(terminated_at, timestamp_ms)
+        });
+        if timestamp_ms < vesting.cliff_at {
+            0
+        } else if timestamp_ms >= vesting.end_at {
+            self.amount
+        } else {
+            mul_div(
+                self.amount,
+                (timestamp_ms - vesting.start_at) as u128,
+                (vesting.end_at - vesting.start_at) as u128,
+            )
+        }
+    }
+
+    /// Vested amount that has not yet been withdrawn from the payout's vesting stream (0 if payout is not vesting)
+    pub fn get_withdrawable_amount(&self, timestamp_ms: TimestampMs) -> u128 {
+        self.vesting.as_ref().map_or(0, |vesting| {
+            self.get_vested_amount(timestamp_ms)
+                .saturating_sub(vesting.withdrawn_amount.0)
+        })
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Amount that `project_id` can currently withdraw from its vesting payouts
+    pub fn get_withdrawable_vested_amount(&self, project_id: ProjectId) -> U128 {
+        let timestamp_ms = env::block_timestamp_ms();
+        U128(
+            self.get_vesting_payouts(&project_id)
+                .iter()
+                .map(|payout| payout.get_withdrawable_amount(timestamp_ms))
+                .sum(),
+        )
+    }
+
+    /// Transfers everything that has vested (and not yet been withdrawn) across the caller's vesting payouts to the project's payout recipient. Returns the amount withdrawn.
+    /// Deliberately not subject to `PausableFeature::Payouts` (see `PausableFeature`).
+    pub fn withdraw_vested(&mut self) -> U128 {
+        let project_id = env::predecessor_account_id();
+        let timestamp_ms = env::block_timestamp_ms();
+        let mut withdrawals: Vec<(PayoutId, U128)> = vec![];
+        for mut payout in self.get_vesting_payouts(&project_id) {
+            let amount = payout.get_withdrawable_amount(timestamp_ms);
+            if amount == 0 {
+                continue;
+            }
+            let vesting = payout.vesting.as_mut().expect("no vesting");
+            vesting.withdrawn_amount = U128(vesting.withdrawn_amount.0 + amount);
+            withdrawals.push((payout.id.clone(), U128(amount)));
+            self.payouts_by_id
+                .insert(&payout.id.clone(), &VersionedPayout::Current(payout));
+        }
+        let amount: u128 = withdrawals
+            .iter()
+            .map(|(_payout_id, amount)| amount.0)
+            .sum();
+        assert!(amount > 0, "No vested funds to withdraw");
+        let recipient_id = self.get_payout_recipient(&project_id);
+        self.transfer_funds(&recipient_id, amount).then(
+            Self::ext(env::current_account_id())
+                .with_static_gas(XCC_GAS)
+                .withdraw_vested_callback(project_id, recipient_id, withdrawals),
+        );
+        U128(amount)
+    }
+
+    /// Verifies whether withdrawal transfer completed successfully & reverts the withdrawn amounts if not (so that they can be withdrawn again)
+    #[private] // Public - but only callable by env::current_account_id()
+    pub fn withdraw_vested_callback(
+        &mut self,
+        project_id: ProjectId,
+        recipient_id: AccountId,
+        withdrawals: Vec<(PayoutId, U128)>,
+        #[callback_result] call_result: Result<(), PromiseError>,
+    ) {
+        let amount: u128 = withdrawals
+            .iter()
+            .map(|(_payout_id, amount)| amount.0)
+            .sum();
+        if call_result.is_err() {
+            log!(format!(
+                "Error withdrawing vested amount {:#?} to {} for project {}",
+                amount, recipient_id, project_id
+            ));
+            for (payout_id, amount) in withdrawals {
+                let mut payout =
+                    Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
+                let vesting = payout.vesting.as_mut().expect("no vesting");
+                vesting.withdrawn_amount = U128(vesting.withdrawn_amount.0 - amount.0);
+                self.payouts_by_id
+                    .insert(&payout_id, &VersionedPayout::Current(payout));
+            }
+        } else {
+            log_withdraw_vested_event(&project_id, &recipient_id, amount);
+        }
+    }
+
+    /// Terminates a payout's vesting stream (callable by admin or owner). The project can still withdraw whatever had vested at termination. The unvested remainder is reclaimed (tallied in `reclaimed_payouts_balance`) & swept with the remainder, or transferred to `remainder_recipient` immediately if `transfer_to_remainder_recipient` is true.
+    #[payable]
+    pub fn admin_terminate_payout_vesting(
+        &mut self,
+        payout_id: PayoutId,
+        transfer_to_remainder_recipient: Option<bool>,
+    ) -> PayoutExternal {
+        self.assert_admin_or_greater();
+        self.assert_not_cancelled();
+        let initial_storage_usage = env::storage_usage();
+        let mut payout = Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"));
+        let timestamp_ms = env::block_timestamp_ms();
+        let unvested_amount = payout.amount - payout.get_vested_amount(timestamp_ms);
+        let vesting = payout.vesting.as_mut().expect("Payout is not vesting");
+        assert!(
+            vesting.terminated_at.is_none(),
+            "Vesting has already been terminated"
+        );
+        assert!(unvested_amount > 0, "Payout has already fully vested");
+        vesting.terminated_at = Some(timestamp_ms);
+        self.payouts_by_id
+            .insert(&payout_id, &VersionedPayout::Current(payout.clone()));
+        self.reclaim_payout_amount(&payout_id, unvested_amount);
+        let remainder_recipient = if transfer_to_remainder_recipient.unwrap_or(false) {
+            let remainder_recipient = self
+                .remainder_recipient
+                .get()
+                .expect("Remainder recipient is not set");
+            // if the transfer fails, the amount stays in the Pot & is swept along with the rest of the remainder
+            self.transfer_remainder(remainder_recipient.clone(), unvested_amount, false);
+            Some(remainder_recipient)
+        } else {
+            None
+        };
+        let payout = payout.to_external();
+        log_terminate_payout_vesting_event(
+            &env::predecessor_account_id(),
+            &payout,
+            unvested_amount,
+            remainder_recipient.as_ref(),
+        );
+        refund_deposit(initial_storage_usage);
+        payout
+    }
+
+    /// Starts a released payout's vesting stream (in place of transferring the funds), according to the Pot's vesting schedule
+    pub(crate) fn start_payout_vesting(
+        &mut self,
+        mut payout: Payout,
+        schedule: PayoutVestingSchedule,
+    ) {
+        let timestamp_ms = env::block_timestamp_ms();
+        payout.vesting = Some(PayoutVesting {
+            start_at: timestamp_ms,
+            cliff_at: timestamp_ms + schedule.cliff_period_ms,
+            end_at: timestamp_ms + schedule.vesting_period_ms,
+            withdrawn_amount: U128(0),
+            terminated_at: None,
+        });
+        payout.paid_at = Some(timestamp_ms);
+        log_start_payout_vesting_event(&payout.to_external());
+        self.payouts_by_id
+            .insert(&payout.id.clone(), &VersionedPayout::Current(payout));
+    }
+
+    pub(crate) fn get_vesting_payouts(&self, project_id: &ProjectId) -> Vec<Payout> {
+        self.payout_ids_by_project_id
+            .get(project_id)
+            .map(|payout_ids| {
+                payout_ids
+                    .iter()
+                    .map(|payout_id| {
+                        Payout::from(self.payouts_by_id.get(&payout_id).expect("no payout"))
+                    })
+                    .filter(|payout| payout.vesting.is_some())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
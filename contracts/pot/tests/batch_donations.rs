@@ -0,0 +1,111 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::{Balance, PromiseError};
+use potlock_pot::*;
+
+fn batch_item(project_id: u32, amount: Balance) -> BatchDonationItem {
+    BatchDonationItem {
+        project_id: project(project_id),
+        amount: U128(amount),
+        message: None,
+    }
+}
+
+fn batch_args(deposit: Balance, donations: Vec<BatchDonationItem>) -> BatchDonationArgs {
+    BatchDonationArgs {
+        donor_id: donor(),
+        sent_by: donor(),
+        deposit,
+        donations,
+        referrer_id: None,
+        bypass_protocol_fee: Some(true),
+        custom_chef_fee_basis_points: None,
+    }
+}
+
+fn setup() -> Contract {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    contract
+}
+
+#[test]
+fn batch_donation_is_recorded_and_excess_refunded() {
+    let mut contract = setup();
+    set_context(&donor(), 4 * ONE_NEAR, PUBLIC_ROUND_MS);
+    let donations = unwrap_value(contract.donate_batch(
+        vec![batch_item(1, ONE_NEAR), batch_item(2, 2 * ONE_NEAR)],
+        None,
+        Some(true),
+        None,
+        None,
+    ))
+    .expect("batch should be recorded");
+    assert_eq!(donations.len(), 2);
+    assert_eq!(transferred_to(&donor()), ONE_NEAR);
+    assert_eq!(
+        contract.get_project_stats(project(2)).total_donations.0,
+        2 * ONE_NEAR
+    );
+}
+
+#[test]
+#[should_panic(expected = "Batch contains more than one donation to project project1.near")]
+fn invalid_batch_is_rejected_before_any_promise() {
+    let mut contract = setup();
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract
+        .admin_set_sybil_wrapper_provider("sybil.near".parse().unwrap(), "is_human".to_string());
+
+    set_context(&donor(), 3 * ONE_NEAR, PUBLIC_ROUND_MS);
+    let _ = contract.donate_batch(
+        vec![batch_item(1, ONE_NEAR), batch_item(1, 2 * ONE_NEAR)],
+        None,
+        Some(true),
+        None,
+        None,
+    );
+}
+
+#[test]
+fn rejected_batch_returns_whole_deposit_without_panicking() {
+    let mut contract = setup();
+    let donations = vec![batch_item(1, ONE_NEAR), batch_item(2, ONE_NEAR)];
+
+    // sybil check fails
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    let result =
+        contract.batch_sybil_callback(batch_args(3 * ONE_NEAR, donations.clone()), Ok(false));
+    assert!(unwrap_value(result).is_none());
+    assert_eq!(transferred_to(&donor()), 0);
+
+    // limits changed while the batch was in flight
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.admin_set_donation_limits(DonationLimits {
+        min_amount: None,
+        max_amount: Some(U128(ONE_NEAR / 2)),
+        max_per_donor_per_project: None,
+        max_per_donor: None,
+        max_per_project: None,
+    });
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    assert!(contract
+        .process_batch_donation(batch_args(3 * ONE_NEAR, donations), 0, None)
+        .is_none());
+    assert!(contract.get_public_round_donations(None, None).is_empty());
+
+    // whole deposit is returned once the batch flow resolves, whether it was rejected or failed
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    assert!(contract
+        .donate_batch_callback(donor(), U128(3 * ONE_NEAR), Ok(None))
+        .is_none());
+    assert_eq!(transferred_to(&donor()), 3 * ONE_NEAR);
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    assert!(contract
+        .donate_batch_callback(donor(), U128(3 * ONE_NEAR), Err(PromiseError::Failed))
+        .is_none());
+    assert_eq!(transferred_to(&donor()), 3 * ONE_NEAR);
+}
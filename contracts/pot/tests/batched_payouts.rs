@@ -0,0 +1,241 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use potlock_pot::*;
+
+fn admin() -> AccountId {
+    "admin.near".parse().unwrap()
+}
+
+/// After the cooldown period
+const PAYOUTS_MS: u64 = ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1;
+
+/// Pot with 3 approved projects & a matching pool (returns the matching pool balance)
+fn setup() -> (Contract, u128) {
+    let mut contract = new_pot(PotArgs {
+        admins: Some(vec![admin()]),
+        ..default_pot_args()
+    });
+    for index in 1..=3 {
+        approve_project(&mut contract, &project(index));
+    }
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    (contract, matching_pool_balance)
+}
+
+fn payout(index: u32, amount: u128) -> PayoutInput {
+    PayoutInput {
+        project_id: project(index),
+        amount: U128(amount),
+    }
+}
+
+/// Sets payouts of 2 NEAR to project 1, 3 NEAR to project 2 & the rest of the matching pool to project 3, in two batches
+fn set_payouts(contract: &mut Contract, matching_pool_balance: u128) {
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![payout(1, 2 * ONE_NEAR)],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_set_payouts(
+        vec![
+            payout(2, 3 * ONE_NEAR),
+            payout(3, matching_pool_balance - 5 * ONE_NEAR),
+        ],
+        None,
+    );
+    contract.chef_finalize_payouts(None);
+}
+
+fn payout_id(contract: &Contract, index: u32) -> PayoutId {
+    contract
+        .get_payouts(None, None)
+        .into_iter()
+        .find(|payout| payout.project_id == project(index) && payout.clawed_back_at.is_none())
+        .expect("no payout")
+        .id
+}
+
+#[test]
+fn payouts_take_effect_once_finalized() {
+    let (mut contract, matching_pool_balance) = setup();
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![payout(1, 2 * ONE_NEAR)],
+        Some("Manual payouts".to_string()),
+    );
+    let draft = contract.chef_set_payouts(
+        vec![
+            payout(2, 3 * ONE_NEAR),
+            payout(3, matching_pool_balance - 5 * ONE_NEAR),
+        ],
+        None,
+    );
+    assert_eq!(draft.total_amount.0, matching_pool_balance);
+    assert!(contract.get_config().cooldown_end_ms.is_none());
+
+    let draft = contract
+        .chef_finalize_payouts(Some(2))
+        .expect("draft should not be finalized yet");
+    assert_eq!(draft.project_index, 2);
+    assert!(contract.get_config().cooldown_end_ms.is_none());
+
+    assert!(contract.chef_finalize_payouts(Some(2)).is_none());
+    assert!(contract.get_payouts_draft().is_none());
+    assert_eq!(
+        contract.get_config().cooldown_end_ms,
+        Some(ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS)
+    );
+    assert_eq!(contract.get_payouts(None, None).len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Total payouts must equal matching pool balance")]
+fn finalized_payouts_must_equal_matching_pool_balance() {
+    let (mut contract, _matching_pool_balance) = setup();
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![payout(1, 2 * ONE_NEAR)],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+}
+
+#[test]
+#[should_panic(expected = "Payouts are being finalized")]
+fn payouts_cannot_be_added_while_finalizing() {
+    let (mut contract, _matching_pool_balance) = setup();
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![payout(1, 2 * ONE_NEAR)],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(Some(1));
+    contract.chef_set_payouts(vec![payout(2, ONE_NEAR)], None);
+}
+
+#[test]
+#[should_panic(expected = "Payout has already been set for project")]
+fn project_cannot_be_given_two_payouts() {
+    let (mut contract, _matching_pool_balance) = setup();
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![payout(1, 2 * ONE_NEAR)],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_set_payouts(vec![payout(1, ONE_NEAR)], None);
+}
+
+#[test]
+#[should_panic(expected = "Existing payouts must first be cleared via chef_clear_payouts")]
+fn payouts_must_be_cleared_before_being_set_again() {
+    let (mut contract, matching_pool_balance) = setup();
+    set_payouts(&mut contract, matching_pool_balance);
+    contract.chef_set_payouts(
+        vec![payout(1, matching_pool_balance)],
+        Some("Manual payouts".to_string()),
+    );
+}
+
+#[test]
+fn payouts_are_cleared_in_batches() {
+    let (mut contract, matching_pool_balance) = setup();
+    set_payouts(&mut contract, matching_pool_balance);
+    assert_eq!(contract.chef_clear_payouts(Some(2)), 1);
+    // payouts no longer take effect once clearing has started
+    assert!(contract.get_config().cooldown_end_ms.is_none());
+    assert_eq!(contract.get_payouts(None, None).len(), 1);
+    assert_eq!(contract.chef_clear_payouts(None), 0);
+    assert!(contract.get_payouts(None, None).is_empty());
+
+    // payouts can then be set again
+    contract.chef_set_payouts(
+        vec![payout(1, matching_pool_balance)],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    assert_eq!(contract.get_payouts(None, None).len(), 1);
+}
+
+#[test]
+fn processing_cursor_is_stable_across_redirects() {
+    let (mut contract, matching_pool_balance) = setup();
+    set_payouts(&mut contract, matching_pool_balance);
+    // project 1's payout is replaced by two milestone tranches
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payout_milestones(
+        project(1),
+        vec![
+            PayoutMilestoneInput {
+                description: "First deliverable".to_string(),
+                amount: U128(ONE_NEAR),
+                due_at: PAYOUTS_MS,
+            },
+            PayoutMilestoneInput {
+                description: "Second deliverable".to_string(),
+                amount: U128(ONE_NEAR),
+                due_at: PAYOUTS_MS,
+            },
+        ],
+    );
+
+    set_context(&admin(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(Some(2));
+    assert_eq!(transferred_to(&project(2)), 3 * ONE_NEAR);
+
+    // a tranche clawed back mid-processing is redirected (& released) as a new payout
+    let tranche_id = payout_id(&contract, 1);
+    set_context(&admin(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_clawback_payout_milestone(tranche_id, Some(project(2)));
+    assert_eq!(transferred_to(&project(2)), ONE_NEAR);
+
+    // no payout is skipped or paid out twice
+    set_context(&admin(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(None);
+    assert_eq!(transferred_to(&project(2)), 0);
+    assert_eq!(
+        transferred_to(&project(3)),
+        matching_pool_balance - 5 * ONE_NEAR
+    );
+}
+
+#[test]
+fn all_paid_out_waits_for_pending_and_failed_payouts() {
+    let (mut contract, matching_pool_balance) = setup();
+    set_payouts(&mut contract, matching_pool_balance);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payout_milestones(
+        project(1),
+        vec![PayoutMilestoneInput {
+            description: "Deliverable".to_string(),
+            amount: U128(2 * ONE_NEAR),
+            due_at: PAYOUTS_MS,
+        }],
+    );
+    let project_2_payout_id = payout_id(&contract, 2);
+    let project_3_payout_id = payout_id(&contract, 3);
+    let tranche_id = payout_id(&contract, 1);
+
+    set_context(&admin(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(None);
+    // transfers are in flight
+    assert!(!contract.get_config().all_paid_out);
+    complete_payout_transfer(&mut contract, &project_2_payout_id, false, PAYOUTS_MS);
+    complete_payout_transfer(&mut contract, &project_3_payout_id, true, PAYOUTS_MS);
+    // project 2's transfer failed & project 1's tranche is pending
+    assert!(!contract.get_config().all_paid_out);
+
+    set_context(&admin(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_retry_failed_payout(project_2_payout_id.clone(), None);
+    complete_payout_transfer(&mut contract, &project_2_payout_id, true, PAYOUTS_MS);
+    assert!(!contract.get_config().all_paid_out);
+
+    set_context(&chef(), ONE_NEAR, PAYOUTS_MS);
+    contract.chef_complete_payout_milestone(tranche_id.clone());
+    assert!(!contract.get_config().all_paid_out);
+    complete_payout_transfer(&mut contract, &tranche_id, true, PAYOUTS_MS);
+    assert!(contract.get_config().all_paid_out);
+}
@@ -0,0 +1,107 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use potlock_pot::*;
+
+fn challenger() -> AccountId {
+    "challenger.near".parse().unwrap()
+}
+
+fn remainder_recipient() -> AccountId {
+    "treasury.near".parse().unwrap()
+}
+
+/// During the cooldown period
+const COOLDOWN_MS: u64 = ROUND_CLOSED_MS + 1_000;
+/// After the cooldown period
+const PAYOUTS_MS: u64 = ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1;
+const VESTING_PERIOD_MS: u64 = 10_000;
+
+fn set_payouts(contract: &mut Contract, amounts: &[(u32, u128)]) {
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        amounts
+            .iter()
+            .map(|(project_index, amount)| PayoutInput {
+                project_id: project(*project_index),
+                amount: U128(*amount),
+            })
+            .collect(),
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+}
+
+/// Cancels the Pot & refunds the (owner's) matching pool donation, returning the refunded amount
+fn cancel_and_refund(contract: &mut Contract, timestamp_ms: u64) -> u128 {
+    set_context(&owner(), ONE_NEAR, timestamp_ms);
+    contract.admin_cancel_pot();
+    set_context(&owner(), ONE_NEAR, timestamp_ms);
+    contract.admin_process_matching_pool_refunds(None, None);
+    transferred_to(&owner())
+}
+
+#[test]
+fn forfeited_challenge_bonds_are_refunded_to_matching_pool_donors() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    set_payouts(&mut contract, &[(1, matching_pool_balance)]);
+    set_context(&challenger(), 2 * ONE_NEAR, COOLDOWN_MS);
+    contract.challenge_payouts("Project 1 is a sybil".to_string());
+    set_context(&owner(), ONE_NEAR, COOLDOWN_MS);
+    contract.admin_update_payouts_challenge(
+        challenger(),
+        None,
+        Some(PayoutsChallengeOutcome::Dismissed),
+    );
+
+    // nothing has been paid out, so matching pool donations are refunded in full, along with the forfeited bond
+    assert_eq!(
+        cancel_and_refund(&mut contract, COOLDOWN_MS),
+        matching_pool_balance + DEFAULT_PAYOUTS_CHALLENGE_BOND
+    );
+}
+
+#[test]
+fn swept_vesting_remainder_is_not_refunded() {
+    let mut contract = new_pot(PotArgs {
+        payout_vesting_schedule: Some(PayoutVestingSchedule {
+            cliff_period_ms: 0,
+            vesting_period_ms: VESTING_PERIOD_MS,
+        }),
+        remainder_recipient: Some(remainder_recipient()),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    donate_to_matching_pool(&mut contract, 20 * ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    set_payouts(
+        &mut contract,
+        &[
+            (1, 10 * ONE_NEAR),
+            (2, matching_pool_balance - 10 * ONE_NEAR),
+        ],
+    );
+    // only project 1's payout starts vesting
+    set_context(&owner(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(Some(1));
+    let payout_id = contract.get_payouts(None, None).remove(0).id;
+
+    // half vested, the rest is transferred to the remainder recipient
+    let terminated_ms = PAYOUTS_MS + VESTING_PERIOD_MS / 2;
+    set_context(&owner(), ONE_NEAR, terminated_ms);
+    contract.admin_terminate_payout_vesting(payout_id, Some(true));
+    set_callback_context(terminated_ms, vec![]);
+    contract.sweep_remainder_callback(remainder_recipient(), U128(5 * ONE_NEAR), Ok(()));
+    assert_eq!(contract.get_config().swept_remainder_amount.0, 5 * ONE_NEAR);
+
+    assert_eq!(
+        cancel_and_refund(&mut contract, terminated_ms),
+        matching_pool_balance - 10 * ONE_NEAR
+    );
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use potlock_pot::*;
+
+fn admin() -> AccountId {
+    "admin.near".parse().unwrap()
+}
+
+fn challenger() -> AccountId {
+    "challenger.near".parse().unwrap()
+}
+
+fn usdc() -> AccountId {
+    "usdc.near".parse().unwrap()
+}
+
+/// During the cooldown period
+const COOLDOWN_MS: u64 = ROUND_CLOSED_MS + 1_000;
+
+/// Pot with payouts set & in cooldown. Matching pool is funded in `base_currency` (NEAR if None).
+fn setup(base_currency: Option<AccountId>) -> Contract {
+    let is_ft = base_currency.is_some();
+    let mut contract = new_pot(PotArgs {
+        admins: Some(vec![admin()]),
+        base_currency,
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    if is_ft {
+        // FT donors cover storage via storage deposits
+        set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+        contract.storage_deposit();
+        set_context(&usdc(), 0, PUBLIC_ROUND_MS);
+        let _ = contract.ft_on_transfer(
+            owner(),
+            U128(1_000_000),
+            r#"{"matching_pool": true, "bypass_protocol_fee": true}"#.to_string(),
+        );
+    } else {
+        donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    }
+    let matching_pool_balance = contract.get_config().matching_pool_balance;
+    assert!(matching_pool_balance.0 > 0);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(1),
+            amount: matching_pool_balance,
+        }],
+        Some("Single project".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    set_context(&challenger(), 2 * ONE_NEAR, COOLDOWN_MS);
+    contract.challenge_payouts("Project 1 is a sybil".to_string());
+    contract
+}
+
+fn resolve_challenge(contract: &mut Contract, outcome: PayoutsChallengeOutcome) {
+    set_context(&admin(), ONE_NEAR, COOLDOWN_MS);
+    contract.admin_update_payouts_challenge(challenger(), None, Some(outcome));
+}
+
+#[test]
+fn dismissed_bond_is_forfeited_to_matching_pool() {
+    let mut contract = setup(None);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+
+    resolve_challenge(&mut contract, PayoutsChallengeOutcome::Dismissed);
+    let config = contract.get_config();
+    assert_eq!(
+        config.matching_pool_balance.0,
+        matching_pool_balance + DEFAULT_PAYOUTS_CHALLENGE_BOND
+    );
+    assert_eq!(
+        config.forfeited_challenge_bonds.0,
+        DEFAULT_PAYOUTS_CHALLENGE_BOND
+    );
+    assert_eq!(transferred_to(&owner()), 0);
+    assert_eq!(transferred_to(&challenger()), 0);
+    // payouts stand
+    assert_eq!(contract.get_payouts(None, None).len(), 1);
+}
+
+#[test]
+fn dismissed_bond_is_forfeited_to_owner_in_ft_pot() {
+    let mut contract = setup(Some(usdc()));
+    let matching_pool_balance = contract.get_config().matching_pool_balance;
+
+    resolve_challenge(&mut contract, PayoutsChallengeOutcome::Dismissed);
+    let config = contract.get_config();
+    // NEAR bond can't be added to the FT matching pool
+    assert_eq!(config.matching_pool_balance, matching_pool_balance);
+    assert_eq!(config.forfeited_challenge_bonds.0, 0);
+    assert_eq!(transferred_to(&owner()), DEFAULT_PAYOUTS_CHALLENGE_BOND);
+    assert_eq!(transferred_to(&challenger()), 0);
+}
+
+#[test]
+fn upheld_bond_is_refunded_and_payouts_cleared() {
+    let mut contract = setup(None);
+    resolve_challenge(&mut contract, PayoutsChallengeOutcome::Upheld);
+    assert_eq!(
+        transferred_to(&challenger()),
+        DEFAULT_PAYOUTS_CHALLENGE_BOND
+    );
+    assert_eq!(transferred_to(&owner()), 0);
+    assert_eq!(contract.get_config().forfeited_challenge_bonds.0, 0);
+    // payouts no longer take effect, & are cleared by the chef before being set again
+    let config = contract.get_config();
+    assert!(config.cooldown_end_ms.is_none());
+    assert!(config.payouts_review_required);
+    set_context(&chef(), ONE_NEAR, COOLDOWN_MS);
+    assert_eq!(contract.chef_clear_payouts(None), 0);
+    assert!(contract.get_payouts(None, None).is_empty());
+    assert!(!contract.get_config().payouts_review_required);
+}
+
+#[test]
+#[should_panic(expected = "Payouts challenge has already been resolved")]
+fn challenge_cannot_be_resolved_twice() {
+    let mut contract = setup(None);
+    resolve_challenge(&mut contract, PayoutsChallengeOutcome::Dismissed);
+    resolve_challenge(&mut contract, PayoutsChallengeOutcome::Upheld);
+}
+
+#[test]
+#[should_panic(expected = "Cooldown period is not in process")]
+fn payouts_cannot_be_challenged_after_cooldown() {
+    let mut contract = setup(None);
+    set_context(
+        &challenger(),
+        2 * ONE_NEAR,
+        ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1,
+    );
+    contract.challenge_payouts("Too late".to_string());
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use potlock_pot::*;
+
+/// After the cooldown period
+const CLAIM_MS: u64 = ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1;
+const CLAIM_DEADLINE_MS: u64 = CLAIM_MS + 1_000;
+
+/// Claimable Pot with payouts of 3 NEAR to project 1 & the rest of the matching pool to project 2, where only project 1 claims its payout. Returns the Pot & project 2's unclaimed payout amount.
+fn setup() -> (Contract, u128) {
+    let mut contract = new_pot(PotArgs {
+        payouts_claimable: Some(true),
+        claim_deadline_ms: Some(CLAIM_DEADLINE_MS),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let unclaimed_amount = contract.get_config().matching_pool_balance.0 - 3 * ONE_NEAR;
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(3 * ONE_NEAR),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(unclaimed_amount),
+            },
+        ],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    set_context(&project(1), 1, CLAIM_MS);
+    contract.claim_payout();
+    (contract, unclaimed_amount)
+}
+
+#[test]
+fn unclaimed_payouts_are_reclaimed_after_deadline() {
+    let (mut contract, unclaimed_amount) = setup();
+    set_context(&owner(), ONE_NEAR, CLAIM_DEADLINE_MS + 1);
+    contract.admin_sweep_unclaimed_payouts(None, None);
+    assert_eq!(
+        contract.get_config().reclaimed_payouts_balance.0,
+        unclaimed_amount
+    );
+    let payouts = contract.get_payouts(None, None);
+    let unclaimed = payouts
+        .iter()
+        .find(|payout| payout.project_id == project(2))
+        .unwrap();
+    assert!(unclaimed.clawed_back_at.is_some());
+
+    // already reclaimed payouts are not reclaimed again
+    set_context(&owner(), ONE_NEAR, CLAIM_DEADLINE_MS + 1);
+    contract.admin_sweep_unclaimed_payouts(None, None);
+    assert_eq!(
+        contract.get_config().reclaimed_payouts_balance.0,
+        unclaimed_amount
+    );
+}
+
+#[test]
+#[should_panic(expected = "Payouts are currently paused")]
+fn unclaimed_payouts_cannot_be_swept_while_paused() {
+    let (mut contract, _unclaimed_amount) = setup();
+    set_context(&owner(), ONE_NEAR, CLAIM_DEADLINE_MS + 1);
+    contract.admin_pause(vec![PausableFeature::Payouts]);
+    set_context(&owner(), ONE_NEAR, CLAIM_DEADLINE_MS + 1);
+    contract.admin_sweep_unclaimed_payouts(None, None);
+}
+
+#[test]
+#[should_panic(expected = "Pot has been cancelled")]
+fn unclaimed_payouts_cannot_be_swept_once_cancelled() {
+    let (mut contract, _unclaimed_amount) = setup();
+    set_context(&owner(), ONE_NEAR, CLAIM_DEADLINE_MS + 1);
+    contract.admin_cancel_pot();
+    set_context(&owner(), ONE_NEAR, CLAIM_DEADLINE_MS + 1);
+    contract.admin_sweep_unclaimed_payouts(None, None);
+}
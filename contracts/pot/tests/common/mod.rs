@@ -0,0 +1,253 @@
+#![allow(dead_code)]
+
+use near_sdk::json_types::U128;
+use near_sdk::mock::VmAction;
+use near_sdk::test_utils::{get_created_receipts, VMContextBuilder};
+use near_sdk::{
+    testing_env, AccountId, Balance, PromiseError, PromiseOrValue, PromiseResult,
+    RuntimeFeesConfig, VMConfig,
+};
+use potlock_pot::*;
+
+pub const ONE_NEAR: Balance = 1_000_000_000_000_000_000_000_000;
+
+pub const APPLICATION_START_MS: u64 = 1_000;
+pub const APPLICATION_END_MS: u64 = 2_000;
+pub const PUBLIC_ROUND_START_MS: u64 = 3_000;
+pub const PUBLIC_ROUND_END_MS: u64 = 4_000;
+/// Any time during the public round
+pub const PUBLIC_ROUND_MS: u64 = 3_500;
+/// Any time after the public round has closed
+pub const ROUND_CLOSED_MS: u64 = 5_000;
+
+pub fn pot_id() -> AccountId {
+    "pot.near".parse().unwrap()
+}
+
+pub fn owner() -> AccountId {
+    "owner.near".parse().unwrap()
+}
+
+pub fn chef() -> AccountId {
+    "chef.near".parse().unwrap()
+}
+
+pub fn donor() -> AccountId {
+    "donor.near".parse().unwrap()
+}
+
+pub fn project(index: u32) -> AccountId {
+    format!("project{}.near", index).parse().unwrap()
+}
+
+/// Sets up a call from `predecessor_id` (also the signer) to the Pot
+pub fn set_context(predecessor_id: &AccountId, deposit: Balance, timestamp_ms: u64) {
+    testing_env!(VMContextBuilder::new()
+        .current_account_id(pot_id())
+        .signer_account_id(predecessor_id.clone())
+        .predecessor_account_id(predecessor_id.clone())
+        .attached_deposit(deposit)
+        .block_timestamp(timestamp_ms * 1_000_000)
+        .account_balance(10_000 * ONE_NEAR)
+        .build());
+}
+
+/// Sets up a callback from the Pot to itself, with the given results of the promises it was chained to
+pub fn set_callback_context(timestamp_ms: u64, promise_results: Vec<PromiseResult>) {
+    testing_env!(
+        VMContextBuilder::new()
+            .current_account_id(pot_id())
+            .signer_account_id(donor())
+            .predecessor_account_id(pot_id())
+            .block_timestamp(timestamp_ms * 1_000_000)
+            .account_balance(10_000 * ONE_NEAR)
+            .build(),
+        VMConfig::test(),
+        RuntimeFeesConfig::test(),
+        Default::default(),
+        promise_results,
+    );
+}
+
+pub fn default_pot_args() -> PotArgs {
+    PotArgs {
+        owner: Some(owner()),
+        admins: None,
+        chef: Some(chef()),
+        reviewers: None,
+        pot_name: "Test Pot".to_string(),
+        pot_description: "Test Pot description".to_string(),
+        max_projects: 20,
+        base_currency: None,
+        accepted_currencies: None,
+        price_oracle_provider: None,
+        application_start_ms: APPLICATION_START_MS,
+        application_end_ms: APPLICATION_END_MS,
+        application_schema: None,
+        review_quorum: None,
+        public_round_start_ms: PUBLIC_ROUND_START_MS,
+        public_round_end_ms: PUBLIC_ROUND_END_MS,
+        registry_provider: None,
+        min_matching_pool_donation_amount: None,
+        donation_limits: None,
+        escrow_public_donations: None,
+        pairwise_match_threshold: None,
+        cooldown_period_ms: None,
+        payouts_challenge_bond: None,
+        payouts_claimable: None,
+        claim_deadline_ms: None,
+        payout_vesting_schedule: None,
+        remainder_recipient: None,
+        sybil_wrapper_provider: None,
+        custom_sybil_checks: None,
+        custom_min_threshold_score: None,
+        referral_fee_matching_pool_basis_points: 0,
+        referral_fee_public_round_basis_points: 0,
+        chef_fee_basis_points: 0,
+        protocol_config_provider: None,
+        source_metadata: ContractSourceMetadata {
+            version: "0.1.0".to_string(),
+            commit_hash: "0".to_string(),
+            link: "https://github.com/PotLock/core".to_string(),
+        },
+    }
+}
+
+pub fn new_pot(pot_args: PotArgs) -> Contract {
+    set_context(&owner(), 0, 0);
+    Contract::new(pot_args)
+}
+
+/// Applies to the Pot as `project_id` & has the chef approve the application
+pub fn approve_project(contract: &mut Contract, project_id: &AccountId) {
+    set_context(project_id, ONE_NEAR, APPLICATION_START_MS);
+    let _ = contract.apply(None, None, None);
+    set_context(&chef(), ONE_NEAR, APPLICATION_START_MS);
+    contract.chef_set_application_status(
+        project_id.clone(),
+        ApplicationStatus::Approved,
+        "Approved".to_string(),
+    );
+}
+
+/// Public round donation from `donor_id` to `project_id` (bypassing protocol fee), returning the resulting promise or value
+pub fn donate(
+    contract: &mut Contract,
+    donor_id: &AccountId,
+    project_id: &AccountId,
+    amount: Balance,
+) -> PromiseOrValue<Option<DonationExternal>> {
+    set_context(donor_id, amount, PUBLIC_ROUND_MS);
+    contract.donate(
+        Some(project_id.clone()),
+        None,
+        None,
+        None,
+        Some(true),
+        None,
+        None,
+        None,
+    )
+}
+
+/// Matching pool donation from the owner
+pub fn donate_to_matching_pool(contract: &mut Contract, amount: Balance) {
+    set_context(&owner(), amount, PUBLIC_ROUND_MS);
+    let _ = contract.donate(None, None, None, Some(true), Some(true), None, None, None);
+}
+
+/// Arguments of a public round donation, as passed along the donation flow
+pub fn donation_args(
+    donor_id: &AccountId,
+    project_id: &AccountId,
+    deposit: Balance,
+) -> DonationArgs {
+    DonationArgs {
+        donor_id: donor_id.clone(),
+        sent_by: donor_id.clone(),
+        deposit,
+        project_id: Some(project_id.clone()),
+        message: None,
+        referrer_id: None,
+        matching_pool: false,
+        earmark: None,
+        bypass_protocol_fee: Some(true),
+        custom_chef_fee_basis_points: None,
+        currency: None,
+    }
+}
+
+pub fn unwrap_value<T>(result: PromiseOrValue<T>) -> T {
+    match result {
+        PromiseOrValue::Value(value) => value,
+        PromiseOrValue::Promise(_) => panic!("Expected a value, got a promise"),
+    }
+}
+
+pub fn is_promise<T>(result: &PromiseOrValue<T>) -> bool {
+    matches!(result, PromiseOrValue::Promise(_))
+}
+
+/// Total NEAR transferred to `receiver_id` by receipts created in the current context
+/// Settles a payout transfer, as its callback would
+pub fn complete_payout_transfer(
+    contract: &mut Contract,
+    payout_id: &PayoutId,
+    succeeded: bool,
+    timestamp_ms: u64,
+) {
+    let payout = contract
+        .get_payouts(None, None)
+        .into_iter()
+        .find(|payout| &payout.id == payout_id)
+        .expect("no payout");
+    let recipient_id = payout.project_id.clone();
+    set_callback_context(timestamp_ms, vec![]);
+    contract.transfer_payout_callback(
+        Payout {
+            id: payout.id,
+            project_id: payout.project_id,
+            amount: payout.amount.0,
+            paid_at: payout.paid_at,
+            milestone: payout.milestone,
+            clawed_back_at: payout.clawed_back_at,
+            escrowed_donations: payout.escrowed_donations,
+            vesting: payout.vesting,
+        },
+        recipient_id,
+        if succeeded {
+            Ok(())
+        } else {
+            Err(PromiseError::Failed)
+        },
+    );
+}
+
+pub fn transferred_to(receiver_id: &AccountId) -> Balance {
+    get_created_receipts()
+        .iter()
+        .filter(|receipt| &receipt.receiver_id == receiver_id)
+        .flat_map(|receipt| receipt.actions.iter())
+        .map(|action| match action {
+            VmAction::Transfer { deposit } => *deposit,
+            _ => 0,
+        })
+        .sum()
+}
+
+/// Names of the function calls made to `receiver_id` by receipts created in the current context
+pub fn function_calls_to(receiver_id: &AccountId) -> Vec<String> {
+    get_created_receipts()
+        .iter()
+        .filter(|receipt| &receipt.receiver_id == receiver_id)
+        .flat_map(|receipt| receipt.actions.iter())
+        .filter_map(|action| match action {
+            VmAction::FunctionCall { function_name, .. } => Some(function_name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn u128_result(value: Balance) -> PromiseResult {
+    PromiseResult::Successful(near_sdk::serde_json::to_vec(&U128(value)).unwrap())
+}
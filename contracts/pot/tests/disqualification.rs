@@ -0,0 +1,126 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::get_logs;
+use potlock_pot::*;
+
+/// Some time into the cooldown period
+const DISQUALIFY_MS: u64 = ROUND_CLOSED_MS + 1_000;
+
+/// Escrowing Pot with donations to projects 1 & 2, and payouts of 3 NEAR to project 1 & the rest of the matching pool to project 2
+fn setup() -> Contract {
+    let mut contract = new_pot(PotArgs {
+        escrow_public_donations: Some(true),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let _ = donate(&mut contract, &donor(), &project(1), ONE_NEAR);
+    let _ = donate(&mut contract, &donor(), &project(2), ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(3 * ONE_NEAR),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(matching_pool_balance - 3 * ONE_NEAR),
+            },
+        ],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    contract
+}
+
+fn disqualify(contract: &mut Contract) -> Application {
+    set_context(&owner(), ONE_NEAR, DISQUALIFY_MS);
+    contract.admin_disqualify_project(project(1), "Sybil donations".to_string())
+}
+
+#[test]
+fn only_disqualified_project_payouts_are_removed() {
+    let mut contract = setup();
+    // matching funds & escrowed donations for each project
+    assert_eq!(contract.get_payouts(None, None).len(), 4);
+    let escrowed_donations_balance = contract.get_config().escrowed_donations_balance;
+
+    let application = disqualify(&mut contract);
+    assert_eq!(application.status, ApplicationStatus::Disqualified);
+    assert_eq!(
+        application.disqualification_reason,
+        Some("Sybil donations".to_string())
+    );
+    let payouts = contract.get_payouts(None, None);
+    assert_eq!(payouts.len(), 2);
+    assert!(payouts.iter().all(|payout| payout.project_id == project(2)));
+
+    let adjust_payouts_log = get_logs()
+        .into_iter()
+        .find(|log| log.contains("\"adjust_payouts\""))
+        .expect("adjust_payouts event should be emitted");
+    assert!(adjust_payouts_log.contains("Sybil donations"));
+    assert!(adjust_payouts_log.contains(&project(1).to_string()));
+    assert!(!adjust_payouts_log.contains(&project(2).to_string()));
+
+    // remaining payouts are flagged for review & the cooldown restarts
+    let config = contract.get_config();
+    assert!(config.payouts_review_required);
+    assert_eq!(
+        config.cooldown_end_ms,
+        Some(DISQUALIFY_MS + DEFAULT_COOLDOWN_PERIOD_MS)
+    );
+    // the disqualified project's escrowed donations stay in escrow, to be refunded
+    assert_eq!(
+        config.escrowed_donations_balance,
+        escrowed_donations_balance
+    );
+
+    // review flag is reset once the chef sets payouts again
+    set_context(&chef(), ONE_NEAR, DISQUALIFY_MS);
+    contract.chef_clear_payouts(None);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(2),
+            amount: contract.get_config().matching_pool_balance,
+        }],
+        Some("Redistributed project 1's matching funds".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    assert!(!contract.get_config().payouts_review_required);
+}
+
+#[test]
+fn disqualification_before_payouts_are_set_leaves_cooldown_unset() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    disqualify(&mut contract);
+    let config = contract.get_config();
+    assert!(config.cooldown_end_ms.is_none());
+    assert!(!config.payouts_review_required);
+    assert!(!get_logs()
+        .iter()
+        .any(|log| log.contains("\"adjust_payouts\"")));
+}
+
+#[test]
+#[should_panic(expected = "Approved application does not exist")]
+fn disqualified_project_cannot_be_paid_out() {
+    let mut contract = setup();
+    disqualify(&mut contract);
+    set_context(&chef(), ONE_NEAR, DISQUALIFY_MS);
+    contract.chef_clear_payouts(None);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(1),
+            amount: U128(ONE_NEAR),
+        }],
+        Some("Reinstate project 1".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::*;
+use near_sdk::{AccountId, PromiseError};
+use potlock_pot::*;
+
+fn intermediary() -> AccountId {
+    "router.near".parse().unwrap()
+}
+
+fn beneficiary() -> AccountId {
+    "beneficiary.near".parse().unwrap()
+}
+
+/// After the round has closed
+const REFUND_MS: u64 = ROUND_CLOSED_MS + 1_000;
+
+/// Escrowing Pot with donations to project 1 from `donor` & (via a trusted intermediary) `beneficiary`, and a donation to project 2 from `donor`. Project 1 is then disqualified.
+fn setup() -> (Contract, DonationExternal, DonationExternal) {
+    let mut contract = new_pot(PotArgs {
+        escrow_public_donations: Some(true),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.admin_add_trusted_intermediaries(vec![intermediary()]);
+
+    let donation = unwrap_value(donate(&mut contract, &donor(), &project(1), ONE_NEAR))
+        .expect("donation should be recorded");
+    set_context(&intermediary(), 2 * ONE_NEAR, PUBLIC_ROUND_MS);
+    let routed_donation = unwrap_value(contract.donate(
+        Some(project(1)),
+        None,
+        None,
+        None,
+        Some(true),
+        None,
+        Some(beneficiary()),
+        None,
+    ))
+    .expect("donation should be recorded");
+    let _ = donate(&mut contract, &donor(), &project(2), ONE_NEAR);
+
+    set_context(&owner(), ONE_NEAR, REFUND_MS);
+    contract.admin_disqualify_project(project(1), "Sybil donations".to_string());
+    (contract, donation, routed_donation)
+}
+
+#[test]
+fn refunded_donations_are_removed_from_project_stats() {
+    let (mut contract, donation, routed_donation) = setup();
+    let stats = contract.get_project_stats(project(1));
+    assert_eq!(
+        stats.net_donations.0,
+        donation.net_amount.0 + routed_donation.net_amount.0
+    );
+
+    set_context(&donor(), 0, REFUND_MS);
+    contract.claim_escrowed_donation_refunds();
+    assert_eq!(transferred_to(&donor()), donation.net_amount.0);
+    let stats = contract.get_project_stats(project(1));
+    assert_eq!(stats.net_donations, routed_donation.net_amount);
+    assert_eq!(stats.donations_count, 1);
+    assert_eq!(stats.unique_donors, 1);
+    assert_eq!(
+        stats.sum_of_sqrt_contributions.0,
+        integer_sqrt(routed_donation.net_amount.0)
+    );
+    // donations to other projects are unaffected
+    assert!(contract.get_project_stats(project(2)).net_donations.0 > 0);
+
+    set_context(&owner(), ONE_NEAR, REFUND_MS);
+    contract.admin_process_escrowed_donation_refunds(None, None);
+    let stats = contract.get_project_stats(project(1));
+    assert_eq!(stats.net_donations.0, 0);
+    assert_eq!(stats.sum_of_sqrt_contributions.0, 0);
+    assert_eq!(stats.total_donations.0, 0);
+    assert_eq!(stats.donations_count, 0);
+    assert_eq!(stats.unique_donors, 0);
+    assert_eq!(
+        contract.get_config().total_public_donations,
+        contract.get_project_stats(project(2)).net_donations
+    );
+    assert_eq!(contract.get_escrowed_donations_balance(project(1)).0, 0);
+}
+
+#[test]
+fn refund_of_routed_donation_goes_to_donor() {
+    let (mut contract, _donation, routed_donation) = setup();
+    assert_eq!(routed_donation.donor_id, beneficiary());
+    assert_eq!(routed_donation.sent_by, intermediary());
+    set_context(&beneficiary(), 0, REFUND_MS);
+    contract.claim_escrowed_donation_refunds();
+    assert_eq!(transferred_to(&beneficiary()), routed_donation.net_amount.0);
+    assert_eq!(transferred_to(&intermediary()), 0);
+}
+
+#[test]
+fn failed_refund_is_returned_to_escrow_and_stats() {
+    let (mut contract, donation, _routed_donation) = setup();
+    let stats = contract.get_project_stats(project(1));
+    set_context(&donor(), 0, REFUND_MS);
+    contract.claim_escrowed_donation_refunds();
+
+    set_callback_context(REFUND_MS, vec![]);
+    contract.transfer_escrowed_donation_refund_callback(donation.id, Err(PromiseError::Failed));
+    let restored_stats = contract.get_project_stats(project(1));
+    assert_eq!(restored_stats.net_donations, stats.net_donations);
+    assert_eq!(
+        restored_stats.sum_of_sqrt_contributions,
+        stats.sum_of_sqrt_contributions
+    );
+    assert_eq!(restored_stats.total_donations, stats.total_donations);
+    assert_eq!(restored_stats.donations_count, stats.donations_count);
+    assert_eq!(restored_stats.unique_donors, stats.unique_donors);
+    let refunded_donation = contract
+        .get_donations_for_donor(donor(), None, None)
+        .into_iter()
+        .find(|refunded_donation| refunded_donation.id == donation.id)
+        .unwrap();
+    assert!(refunded_donation.refunded_at.is_none());
+}
@@ -0,0 +1,154 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use potlock_pot::*;
+use std::collections::HashSet;
+
+fn admin() -> AccountId {
+    "admin.near".parse().unwrap()
+}
+
+/// After the cooldown period
+const PAYOUTS_MS: u64 = ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1;
+
+/// Pot with payouts of 6 NEAR to project 1 & the rest of the matching pool to project 2
+fn setup() -> Contract {
+    let mut contract = new_pot(PotArgs {
+        admins: Some(vec![admin()]),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(6 * ONE_NEAR),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(matching_pool_balance - 6 * ONE_NEAR),
+            },
+        ],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    contract
+}
+
+fn milestone(amount: u128) -> PayoutMilestoneInput {
+    PayoutMilestoneInput {
+        description: "Deliverable".to_string(),
+        amount: U128(amount),
+        due_at: PAYOUTS_MS,
+    }
+}
+
+fn set_milestones(contract: &mut Contract, amounts: &[u128]) -> Vec<PayoutId> {
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payout_milestones(
+        project(1),
+        amounts.iter().map(|amount| milestone(*amount)).collect(),
+    );
+    contract
+        .get_payouts(None, None)
+        .into_iter()
+        .filter(|payout| payout.project_id == project(1))
+        .map(|payout| payout.id)
+        .collect()
+}
+
+fn payout_ids(contract: &Contract) -> Vec<PayoutId> {
+    contract
+        .get_payouts(None, None)
+        .into_iter()
+        .map(|payout| payout.id)
+        .collect()
+}
+
+#[test]
+fn milestone_payout_ids_are_never_reused() {
+    let mut contract = setup();
+    let initial_ids = payout_ids(&contract);
+    let first_ids = set_milestones(&mut contract, &[2 * ONE_NEAR, 4 * ONE_NEAR]);
+    assert_eq!(first_ids.len(), 2);
+    let second_ids = set_milestones(&mut contract, &[ONE_NEAR, 2 * ONE_NEAR, 3 * ONE_NEAR]);
+    assert_eq!(second_ids.len(), 3);
+
+    let mut seen: HashSet<PayoutId> = HashSet::new();
+    for id in initial_ids
+        .iter()
+        .chain(first_ids.iter())
+        .chain(second_ids.iter())
+    {
+        assert!(seen.insert(id.clone()), "payout ID {} was reused", id);
+    }
+    // project 2's payout is untouched
+    assert!(payout_ids(&contract).contains(&initial_ids[1]));
+
+    // redirecting a clawed back tranche creates a payout with a fresh ID
+    set_context(&admin(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_clawback_payout_milestone(second_ids[0].clone(), Some(project(2)));
+    let redirected_ids: Vec<PayoutId> = payout_ids(&contract)
+        .into_iter()
+        .filter(|id| !seen.contains(id))
+        .collect();
+    assert_eq!(redirected_ids.len(), 1);
+    assert!(redirected_ids[0].starts_with(project(2).as_str()));
+    // nothing is reclaimed when a tranche is redirected
+    assert_eq!(contract.get_config().reclaimed_payouts_balance.0, 0);
+}
+
+#[test]
+fn clawed_back_tranche_is_reclaimed() {
+    let mut contract = setup();
+    let ids = set_milestones(&mut contract, &[2 * ONE_NEAR, 4 * ONE_NEAR]);
+    set_context(&admin(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_clawback_payout_milestone(ids[1].clone(), None);
+    assert_eq!(
+        contract.get_config().reclaimed_payouts_balance.0,
+        4 * ONE_NEAR
+    );
+
+    // completed tranche is paid out, clawed back tranche is not
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_complete_payout_milestone(ids[0].clone());
+    set_context(&admin(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(None);
+    assert_eq!(transferred_to(&project(1)), 2 * ONE_NEAR);
+}
+
+#[test]
+fn reclaimed_amount_is_reset_with_payouts() {
+    let mut contract = setup();
+    let ids = set_milestones(&mut contract, &[2 * ONE_NEAR, 4 * ONE_NEAR]);
+    set_context(&admin(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_clawback_payout_milestone(ids[0].clone(), None);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_clear_payouts(None);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(1),
+            amount: contract.get_config().matching_pool_balance,
+        }],
+        Some("Reset payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    assert_eq!(contract.get_config().reclaimed_payouts_balance.0, 0);
+}
+
+#[test]
+#[should_panic(expected = "Payout has already been clawed back")]
+fn tranche_cannot_be_clawed_back_twice() {
+    let mut contract = setup();
+    let ids = set_milestones(&mut contract, &[2 * ONE_NEAR, 4 * ONE_NEAR]);
+    set_context(&admin(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_clawback_payout_milestone(ids[0].clone(), None);
+    set_context(&admin(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_clawback_payout_milestone(ids[0].clone(), None);
+}
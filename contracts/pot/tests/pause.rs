@@ -0,0 +1,60 @@
+mod common;
+
+use common::*;
+use potlock_pot::*;
+
+#[test]
+fn donations_resume_once_unpaused() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.admin_pause(vec![PausableFeature::Donations]);
+    assert!(contract.is_paused(PausableFeature::Donations));
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.admin_unpause(vec![PausableFeature::Donations]);
+
+    let donation = unwrap_value(donate(&mut contract, &donor(), &project(1), ONE_NEAR))
+        .expect("donation should be recorded");
+    assert_eq!(donation.total_amount.0, ONE_NEAR);
+    assert_eq!(contract.get_public_round_donations(None, None).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Donations are currently paused")]
+fn paused_donations_are_rejected_up_front() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.admin_pause(vec![PausableFeature::Donations]);
+
+    let _ = donate(&mut contract, &donor(), &project(1), ONE_NEAR);
+}
+
+#[test]
+fn donation_in_flight_when_paused_is_returned_without_panicking() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract
+        .admin_set_sybil_wrapper_provider("sybil.near".parse().unwrap(), "is_human".to_string());
+
+    // donation awaits sybil check
+    let result = donate(&mut contract, &donor(), &project(1), ONE_NEAR);
+    assert!(is_promise(&result));
+
+    set_context(&owner(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.admin_pause(vec![PausableFeature::Donations]);
+
+    // sybil check passes, but donations have since been paused
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    let result = contract.sybil_callback(donation_args(&donor(), &project(1), ONE_NEAR), Ok(true));
+    assert!(unwrap_value(result).is_none());
+    assert!(contract.get_public_round_donations(None, None).is_empty());
+
+    // deposit is returned to the donor once the donation flow resolves
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    let donation = contract.donate_callback(donor(), ONE_NEAR.into(), Ok(None));
+    assert!(donation.is_none());
+    assert_eq!(transferred_to(&donor()), ONE_NEAR);
+}
@@ -0,0 +1,46 @@
+mod common;
+
+use common::*;
+use near_sdk::AccountId;
+use potlock_pot::PausableFeature;
+
+fn treasury() -> AccountId {
+    "treasury.near".parse().unwrap()
+}
+
+#[test]
+fn project_can_update_payout_recipient() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    set_context(&project(1), ONE_NEAR, ROUND_CLOSED_MS);
+    let application = contract.update_payout_recipient(Some(treasury()));
+    assert_eq!(application.payout_recipient, Some(treasury()));
+}
+
+#[test]
+#[should_panic(expected = "Chef, admin & owner cannot be payout recipients")]
+fn chef_cannot_be_payout_recipient() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    set_context(&project(1), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.update_payout_recipient(Some(chef()));
+}
+
+#[test]
+#[should_panic(expected = "Chef, admin & owner cannot be payout recipients")]
+fn owner_cannot_be_payout_recipient_when_applying() {
+    let mut contract = new_pot(default_pot_args());
+    set_context(&project(1), ONE_NEAR, APPLICATION_START_MS);
+    let _ = contract.apply(None, None, Some(owner()));
+}
+
+#[test]
+#[should_panic(expected = "Payouts are currently paused")]
+fn payout_recipient_cannot_be_updated_while_payouts_paused() {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    set_context(&owner(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_pause(vec![PausableFeature::Payouts]);
+    set_context(&project(1), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.update_payout_recipient(Some(treasury()));
+}
@@ -0,0 +1,111 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::mock::with_mocked_blockchain;
+use near_sdk::test_utils::VMContextBuilder;
+use near_sdk::{serde_json, testing_env, AccountId, PromiseError, PromiseResult};
+use potlock_pot::*;
+
+fn oracle_id() -> AccountId {
+    "oracle.near".parse().unwrap()
+}
+
+fn near() -> AccountId {
+    "near".parse().unwrap()
+}
+
+fn usdc() -> AccountId {
+    "usdc.near".parse().unwrap()
+}
+
+/// Sets a price of NEAR in USDC on the price oracle simulator & returns its `get_price` response, as it would be returned to the Pot
+fn get_simulator_price(multiplier: u128, decimals: u8) -> Vec<u8> {
+    testing_env!(VMContextBuilder::new()
+        .current_account_id(oracle_id())
+        .predecessor_account_id(owner())
+        .attached_deposit(ONE_NEAR)
+        .build());
+    let mut oracle = price_oracle_simulator::Contract::new();
+    oracle.set_price(near(), usdc(), U128(multiplier), decimals);
+    let price = serde_json::to_vec(&oracle.get_price(near(), usdc())).unwrap();
+    // the oracle's state lives in another account; don't share it with the Pot
+    with_mocked_blockchain(|blockchain| blockchain.take_storage());
+    price
+}
+
+/// USDC-denominated Pot that also accepts NEAR donations
+fn setup() -> Contract {
+    let mut contract = new_pot(PotArgs {
+        base_currency: Some(usdc()),
+        accepted_currencies: Some(vec![near()]),
+        price_oracle_provider: Some(ProviderId(format!("{}:get_price", oracle_id()))),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    contract
+}
+
+fn price_oracle_callback(
+    contract: &mut Contract,
+    price: Result<Vec<u8>, PromiseError>,
+) -> Option<DonationExternal> {
+    let promise_result = match &price {
+        Ok(price) => PromiseResult::Successful(price.clone()),
+        Err(_) => PromiseResult::Failed,
+    };
+    set_callback_context(PUBLIC_ROUND_MS, vec![promise_result]);
+    unwrap_value(contract.price_oracle_callback(
+        donation_args(&donor(), &project(1), ONE_NEAR),
+        near(),
+        price.map(|price| serde_json::from_slice(&price).unwrap()),
+    ))
+}
+
+#[test]
+fn near_donation_is_normalized_into_base_currency() {
+    // 1 yoctoNEAR = 3 / 10^18 USDC units, i.e. 1 NEAR = 3 USDC
+    let price = get_simulator_price(3, 18);
+    let mut contract = setup();
+
+    let result = donate(&mut contract, &donor(), &project(1), ONE_NEAR);
+    assert!(is_promise(&result));
+    // promises are only scheduled once dropped
+    drop(result);
+    assert_eq!(
+        function_calls_to(&oracle_id()),
+        vec!["get_price".to_string()]
+    );
+
+    let donation =
+        price_oracle_callback(&mut contract, Ok(price)).expect("donation should be recorded");
+    assert_eq!(donation.total_amount.0, ONE_NEAR);
+    assert_eq!(donation.normalized_total_amount.0, 3_000_000);
+    assert_eq!(donation.currency.unwrap().currency, near());
+    assert_eq!(
+        contract.get_project_stats(project(1)).total_donations.0,
+        3_000_000
+    );
+    // net amount is transferred to the project in the currency donated
+    assert_eq!(transferred_to(&project(1)), donation.net_amount.0);
+}
+
+#[test]
+fn failed_normalization_returns_donation_without_panicking() {
+    // oracle returns a price that can't be used
+    let zero_price = get_simulator_price(0, 18);
+    let mut contract = setup();
+    assert!(price_oracle_callback(&mut contract, Ok(zero_price)).is_none());
+
+    // oracle call fails (e.g. no price set)
+    assert!(price_oracle_callback(&mut contract, Err(PromiseError::Failed)).is_none());
+    assert!(contract.get_public_round_donations(None, None).is_empty());
+    assert_eq!(transferred_to(&donor()), 0);
+
+    // deposit is returned to the donor once the donation flow resolves
+    set_callback_context(PUBLIC_ROUND_MS, vec![]);
+    assert!(contract
+        .donate_callback(donor(), U128(ONE_NEAR), Ok(None))
+        .is_none());
+    assert_eq!(transferred_to(&donor()), ONE_NEAR);
+}
@@ -0,0 +1,60 @@
+use potlock_pot::*;
+
+const ONE_NEAR: u128 = 1_000_000_000_000_000_000_000_000;
+
+#[test]
+fn integer_sqrt_rounds_down() {
+    assert_eq!(integer_sqrt(0), 0);
+    assert_eq!(integer_sqrt(1), 1);
+    assert_eq!(integer_sqrt(3), 1);
+    assert_eq!(integer_sqrt(4), 2);
+    assert_eq!(integer_sqrt(99), 9);
+    assert_eq!(integer_sqrt(100), 10);
+    let root = 1u128 << 63;
+    assert_eq!(integer_sqrt(root * root), root);
+    assert_eq!(integer_sqrt(root * root - 1), root - 1);
+    assert_eq!(integer_sqrt(u128::MAX), u64::MAX as u128);
+}
+
+#[test]
+fn mul_div_uses_full_precision_intermediate_product() {
+    assert_eq!(mul_div(10, 20, 3), 66);
+    assert_eq!(mul_div(0, 20, 3), 0);
+    // product overflows u128, but the result does not
+    assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX), u128::MAX);
+    assert_eq!(
+        mul_div(5_000 * ONE_NEAR, 3 * ONE_NEAR, 7 * ONE_NEAR),
+        5_000 * ONE_NEAR * 3 / 7
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot divide by zero")]
+fn mul_div_rejects_zero_divisor() {
+    mul_div(1, 1, 0);
+}
+
+#[test]
+#[should_panic(expected = "Overflow occurred")]
+fn mul_div_rejects_overflowing_result() {
+    mul_div(u128::MAX, 2, 1);
+}
+
+#[test]
+fn sqrt_of_product_is_exact_without_overflow() {
+    assert_eq!(sqrt_of_product(0, u128::MAX), 0);
+    assert_eq!(sqrt_of_product(4, 9), 6);
+    assert_eq!(sqrt_of_product(1_000_000, 1_000_000), 1_000_000);
+}
+
+#[test]
+fn sqrt_of_product_keeps_precision_on_overflow() {
+    // yoctoNEAR amounts overflow u128 when multiplied
+    assert_eq!(sqrt_of_product(25 * ONE_NEAR, 25 * ONE_NEAR), 25 * ONE_NEAR);
+    let (a, b) = (10 * ONE_NEAR, 7 * ONE_NEAR);
+    let expected = (a as f64 * b as f64).sqrt();
+    let actual = sqrt_of_product(a, b) as f64;
+    assert!((actual - expected).abs() / expected < 1e-15);
+    let actual = sqrt_of_product(u128::MAX, u128::MAX);
+    assert!(u128::MAX - actual < 1 << 66);
+}
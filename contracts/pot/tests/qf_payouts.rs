@@ -0,0 +1,381 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::get_logs;
+use near_sdk::{AccountId, Balance};
+use potlock_pot::*;
+use std::collections::{BTreeMap, HashMap};
+
+fn user(index: u32) -> AccountId {
+    format!("user{}.near", index).parse().unwrap()
+}
+
+/// Contributions from `PROJECT_CONTRIBUTIONS_EXAMPLE` in test/utils/quadratics.ts (in NEAR)
+const EXAMPLE_CONTRIBUTIONS: [(u32, u32, u128); 16] = [
+    (1, 1, 10),
+    (1, 2, 5),
+    (1, 2, 10),
+    (1, 3, 7),
+    (1, 5, 5),
+    (1, 4, 10),
+    (1, 5, 5),
+    (1, 5, 5),
+    (2, 1, 10),
+    (2, 1, 5),
+    (2, 2, 20),
+    (2, 3, 3),
+    (2, 8, 2),
+    (2, 9, 10),
+    (2, 7, 7),
+    (2, 2, 5),
+];
+
+/// Records the example donations, returning net contributions per project & donor
+fn setup(matching_pool: Balance) -> (Contract, BTreeMap<AccountId, BTreeMap<AccountId, Balance>>) {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    donate_to_matching_pool(&mut contract, matching_pool);
+    let mut contributions: BTreeMap<AccountId, BTreeMap<AccountId, Balance>> = BTreeMap::new();
+    for (project_index, user_index, amount) in EXAMPLE_CONTRIBUTIONS {
+        let donation = unwrap_value(donate(
+            &mut contract,
+            &user(user_index),
+            &project(project_index),
+            amount * ONE_NEAR,
+        ))
+        .expect("donation should be recorded");
+        *contributions
+            .entry(project(project_index))
+            .or_default()
+            .entry(user(user_index))
+            .or_default() += donation.net_amount.0;
+    }
+    (contract, contributions)
+}
+
+/// Reference implementation of `calculateClr` in test/utils/quadratics.ts (before normalization)
+fn expected_scores(
+    contributions: &BTreeMap<AccountId, BTreeMap<AccountId, Balance>>,
+    threshold: Balance,
+) -> HashMap<AccountId, f64> {
+    let mut pair_totals: HashMap<(AccountId, AccountId), f64> = HashMap::new();
+    for contribz in contributions.values() {
+        for (k1, v1) in contribz {
+            for (k2, v2) in contribz {
+                *pair_totals.entry((k1.clone(), k2.clone())).or_default() +=
+                    (*v1 as f64 * *v2 as f64).sqrt();
+            }
+        }
+    }
+    contributions
+        .iter()
+        .map(|(project_id, contribz)| {
+            let mut tot = 0f64;
+            for (k1, v1) in contribz {
+                for (k2, v2) in contribz {
+                    if k2 > k1 {
+                        tot += (*v1 as f64 * *v2 as f64).sqrt()
+                            / (pair_totals[&(k1.clone(), k2.clone())] / threshold as f64);
+                    }
+                }
+            }
+            (project_id.clone(), tot)
+        })
+        .collect()
+}
+
+fn assert_close(actual: u128, expected: f64) {
+    assert!(
+        (actual as f64 - expected).abs() / expected < 1e-9,
+        "expected {} to be close to {}",
+        actual,
+        expected
+    );
+}
+
+/// Runs the QF calculation to completion in batches of `limit` pairs, returning the number of calls made
+fn calculate(contract: &mut Contract, limit: u64) -> u32 {
+    let mut calls = 0;
+    loop {
+        set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+        let calculation = contract.chef_calculate_qf_payouts(Some(limit));
+        calls += 1;
+        if calculation.stage == QfCalculationStage::Complete {
+            return calls;
+        }
+    }
+}
+
+fn matching_amounts(contract: &Contract) -> HashMap<AccountId, Balance> {
+    contract
+        .get_qf_payout_calculations(None, None)
+        .into_iter()
+        .map(|calculation| (calculation.project_id, calculation.matching_amount.0))
+        .collect()
+}
+
+#[test]
+fn batched_calculation_matches_pairwise_bounded_clr() {
+    let (mut contract, contributions) = setup(5_000 * ONE_NEAR);
+    // 10 pairs of donors to project 1, 15 to project 2, processed twice (pair totals, then scores)
+    assert!(calculate(&mut contract, 3) > 10);
+
+    let expected = expected_scores(&contributions, DEFAULT_PAIRWISE_MATCH_THRESHOLD);
+    let calculations = contract.get_qf_payout_calculations(None, None);
+    assert_eq!(calculations.len(), 2);
+    for calculation in calculations.iter() {
+        assert_close(calculation.qf_score.0, expected[&calculation.project_id]);
+        // matching pool isn't saturated, so each project is matched its score
+        assert_eq!(calculation.matching_amount, calculation.qf_score);
+    }
+    let project_1 = calculations
+        .iter()
+        .find(|calculation| calculation.project_id == project(1))
+        .unwrap();
+    assert_eq!(project_1.unique_donors, 5);
+    assert_eq!(
+        project_1.total_donations.0,
+        contributions[&project(1)].values().sum::<u128>()
+    );
+    let page = contract.get_qf_payout_calculations(Some(1), Some(1));
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].project_id, project(2));
+
+    // the rest of the matching pool is left unallocated
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts_from_calculation(None);
+    let total_payouts: u128 = contract
+        .get_payouts(None, None)
+        .iter()
+        .map(|payout| payout.amount.0)
+        .sum();
+    assert!(total_payouts < 5_000 * ONE_NEAR);
+}
+
+#[test]
+fn saturated_matching_pool_is_normalized() {
+    let (mut contract, contributions) = setup(10 * ONE_NEAR);
+    calculate(&mut contract, 100);
+
+    let expected = expected_scores(&contributions, DEFAULT_PAIRWISE_MATCH_THRESHOLD);
+    let total_score: f64 = expected.values().sum();
+    // matching pool donations are net of storage costs
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    let matching_amounts = matching_amounts(&contract);
+    for (project_id, matching_amount) in matching_amounts.iter() {
+        assert_close(
+            *matching_amount,
+            expected[project_id] / total_score * matching_pool_balance as f64,
+        );
+    }
+    assert_eq!(
+        matching_amounts.values().sum::<u128>(),
+        matching_pool_balance
+    );
+}
+
+#[test]
+fn calculated_payouts_are_set_in_batches() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    calculate(&mut contract, 100);
+    let matching_amounts = matching_amounts(&contract);
+
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    let draft = contract
+        .chef_set_payouts_from_calculation(Some(1))
+        .expect("draft should not be finalized yet");
+    assert_eq!(draft.project_index, 1);
+    assert!(contract.get_config().cooldown_end_ms.is_none());
+    assert!(contract.chef_set_payouts_from_calculation(None).is_none());
+    assert!(contract.get_config().cooldown_end_ms.is_some());
+    let payouts = contract.get_payouts(None, None);
+    assert_eq!(payouts.len(), matching_amounts.len());
+    for payout in payouts {
+        assert_eq!(payout.amount.0, matching_amounts[&payout.project_id]);
+    }
+}
+
+#[test]
+fn calculation_restarts_when_inputs_change() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    calculate(&mut contract, 100);
+    assert_eq!(contract.get_qf_calculation().unwrap().epoch, 0);
+
+    set_context(&owner(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_set_pairwise_match_threshold(U128(DEFAULT_PAIRWISE_MATCH_THRESHOLD / 2));
+    let calculation = contract.get_qf_calculation().unwrap();
+    assert_eq!(calculation.epoch, 1);
+    assert_eq!(calculation.stage, QfCalculationStage::Clearing);
+
+    // scores are bounded by the new threshold
+    calculate(&mut contract, 100);
+    assert_eq!(
+        matching_amounts(&contract).values().sum::<u128>(),
+        contract.get_config().matching_pool_balance.0
+    );
+}
+
+#[test]
+#[should_panic(expected = "QF payouts calculation is not complete")]
+fn payouts_cannot_be_set_from_incomplete_calculation() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_calculate_qf_payouts(Some(1));
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts_from_calculation(None);
+}
+
+#[test]
+fn abandoned_intermediate_results_are_cleared() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    calculate(&mut contract, 100);
+    let expected_matching_amounts = matching_amounts(&contract);
+
+    // abandon a calculation partway through summing pair totals
+    set_context(&owner(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_set_pairwise_match_threshold(U128(DEFAULT_PAIRWISE_MATCH_THRESHOLD));
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_calculate_qf_payouts(Some(10));
+    set_context(&owner(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.admin_set_pairwise_match_threshold(U128(DEFAULT_PAIRWISE_MATCH_THRESHOLD));
+    // leftover results are removed before the calculation starts over
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    let calculation = contract.chef_calculate_qf_payouts(Some(1));
+    assert_eq!(calculation.stage, QfCalculationStage::Clearing);
+
+    calculate(&mut contract, 100);
+    assert_eq!(matching_amounts(&contract), expected_matching_amounts);
+}
+
+#[test]
+fn allocation_restarts_when_matching_pool_changes() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    calculate(&mut contract, 100);
+
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let calculation = contract.get_qf_calculation().unwrap();
+    assert_eq!(calculation.epoch, 0);
+    assert_eq!(calculation.stage, QfCalculationStage::ScoreTotals);
+
+    // scores are kept, so only the allocation stages are processed again (one project per step)
+    assert_eq!(calculate(&mut contract, 2), 3);
+    assert_eq!(
+        matching_amounts(&contract).values().sum::<u128>(),
+        contract.get_config().matching_pool_balance.0
+    );
+}
+
+#[test]
+#[should_panic(expected = "Total payouts must equal matching pool balance")]
+fn manual_payouts_must_equal_matching_pool_balance() {
+    let (mut contract, _contributions) = setup(5_000 * ONE_NEAR);
+    calculate(&mut contract, 100);
+    // below saturation, the calculated payouts leave part of the matching pool unallocated
+    let matching_amounts = matching_amounts(&contract);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(matching_amounts[&project(1)]),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(matching_amounts[&project(2)]),
+            },
+        ],
+        None,
+    );
+    contract.chef_finalize_payouts(None);
+}
+
+#[test]
+#[should_panic(expected = "Owner, admins & chef cannot receive payouts")]
+fn owner_admins_and_chef_cannot_receive_payouts() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    set_context(&owner(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.owner_add_admins(vec![project(1)]);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(1),
+            amount: contract.get_config().matching_pool_balance,
+        }],
+        Some("Single project".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+}
+
+#[test]
+fn only_adjusted_payouts_are_logged() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    approve_project(&mut contract, &project(3));
+    calculate(&mut contract, 100);
+    let matching_amounts = matching_amounts(&contract);
+
+    // matching the calculation doesn't require a justification
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(matching_amounts[&project(1)]),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(matching_amounts[&project(2)]),
+            },
+        ],
+        None,
+    );
+    contract.chef_finalize_payouts(None);
+    assert!(!get_logs()
+        .iter()
+        .any(|log| log.contains("\"adjust_payouts\"")));
+
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_clear_payouts(None);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(matching_amounts[&project(1)]),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(matching_amounts[&project(2)] - ONE_NEAR),
+            },
+            PayoutInput {
+                project_id: project(3),
+                amount: U128(ONE_NEAR),
+            },
+        ],
+        Some("Project 2 received donations from sybil accounts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    let adjust_payouts_log = get_logs()
+        .into_iter()
+        .find(|log| log.contains("\"adjust_payouts\""))
+        .expect("adjust_payouts event should be emitted");
+    assert!(adjust_payouts_log.contains(&project(2).to_string()));
+    assert!(adjust_payouts_log.contains(&project(3).to_string()));
+    assert!(!adjust_payouts_log.contains(&project(1).to_string()));
+}
+
+#[test]
+#[should_panic(expected = "Justification must be provided when overriding calculated payouts")]
+fn adjusted_payouts_require_justification() {
+    let (mut contract, _contributions) = setup(10 * ONE_NEAR);
+    calculate(&mut contract, 100);
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(1),
+            amount: U128(ONE_NEAR),
+        }],
+        Some(" ".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use common::*;
+use near_sdk::AccountId;
+use potlock_pot::*;
+
+fn reviewer(index: u32) -> AccountId {
+    format!("reviewer{}.near", index).parse().unwrap()
+}
+
+/// Pot with 3 reviewers (quorum of 2) & a pending application from project 1
+fn setup() -> Contract {
+    let mut contract = new_pot(PotArgs {
+        reviewers: Some(vec![reviewer(1), reviewer(2), reviewer(3)]),
+        ..default_pot_args()
+    });
+    apply(&mut contract);
+    contract
+}
+
+fn apply(contract: &mut Contract) {
+    set_context(&project(1), ONE_NEAR, APPLICATION_START_MS);
+    let _ = contract.apply(None, None, None);
+}
+
+fn vote(contract: &mut Contract, reviewer_index: u32, vote: ReviewVote) -> Application {
+    set_context(&reviewer(reviewer_index), ONE_NEAR, APPLICATION_START_MS);
+    contract.reviewer_vote_on_application(project(1), vote, None)
+}
+
+#[test]
+fn votes_are_stored_per_reviewer_until_quorum() {
+    let mut contract = setup();
+    let application = vote(&mut contract, 1, ReviewVote::Reject);
+    assert_eq!(application.reject_votes_count, 1);
+    // a reviewer's vote replaces their previous vote
+    let application = vote(&mut contract, 1, ReviewVote::Approve);
+    assert_eq!(application.approve_votes_count, 1);
+    assert_eq!(application.reject_votes_count, 0);
+    let votes = contract.get_application_votes(project(1), None, None);
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].reviewer_id, reviewer(1));
+    assert_eq!(votes[0].vote, ReviewVote::Approve);
+
+    let application = vote(&mut contract, 2, ReviewVote::Approve);
+    assert_eq!(application.status, ApplicationStatus::Approved);
+    assert_eq!(
+        contract.get_application_votes(project(1), None, None).len(),
+        2
+    );
+    assert_eq!(
+        contract
+            .get_application_votes(project(1), Some(1), Some(1))
+            .len(),
+        1
+    );
+    let reviews = contract.get_application_review_history(project(1), None, None);
+    assert_eq!(reviews.len(), 1);
+    assert_eq!(reviews[0].reviewer_id, reviewer(2));
+    assert_eq!(reviews[0].status, ApplicationStatus::Approved);
+}
+
+#[test]
+fn votes_are_removed_and_review_history_kept_on_unapply() {
+    let mut contract = setup();
+    set_context(&chef(), ONE_NEAR, APPLICATION_START_MS);
+    contract.chef_set_application_status(
+        project(1),
+        ApplicationStatus::InReview,
+        "Checking".to_string(),
+    );
+    set_context(&chef(), ONE_NEAR, APPLICATION_START_MS);
+    contract.chef_set_application_status(
+        project(1),
+        ApplicationStatus::Pending,
+        "Checked".to_string(),
+    );
+    vote(&mut contract, 1, ReviewVote::Reject);
+    assert_eq!(
+        contract
+            .get_application_review_history(project(1), None, None)
+            .len(),
+        2
+    );
+
+    set_context(&project(1), 0, APPLICATION_START_MS);
+    contract.unapply();
+    // review history remains viewable once the application has been withdrawn
+    assert_eq!(
+        contract
+            .get_application_review_history(project(1), None, None)
+            .len(),
+        2
+    );
+    apply(&mut contract);
+    assert!(contract
+        .get_application_votes(project(1), None, None)
+        .is_empty());
+    // review history is append-only
+    assert_eq!(
+        contract
+            .get_application_review_history(project(1), None, None)
+            .len(),
+        2
+    );
+    let application = vote(&mut contract, 2, ReviewVote::Reject);
+    assert_eq!(application.reject_votes_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "Only reviewers can call this method")]
+fn only_reviewers_can_vote() {
+    let mut contract = setup();
+    set_context(&donor(), ONE_NEAR, APPLICATION_START_MS);
+    contract.reviewer_vote_on_application(project(1), ReviewVote::Approve, None);
+}
+
+#[test]
+#[should_panic(expected = "Must attach")]
+fn chef_pays_for_review_history_storage() {
+    let mut contract = setup();
+    set_context(&chef(), 1, APPLICATION_START_MS);
+    contract.chef_set_application_status(
+        project(1),
+        ApplicationStatus::InReview,
+        "Checking".to_string(),
+    );
+}
+
+#[test]
+fn votes_are_retallied_when_reviewers_change() {
+    let mut contract = setup();
+    vote(&mut contract, 1, ReviewVote::Approve);
+    vote(&mut contract, 2, ReviewVote::Reject);
+    set_context(&owner(), ONE_NEAR, APPLICATION_START_MS);
+    contract.admin_remove_reviewers(vec![reviewer(1)]);
+    let application = contract.get_application_by_project_id(project(1));
+    assert_eq!(application.approve_votes_count, 0);
+    assert_eq!(application.reject_votes_count, 1);
+    // majority of 2 remaining reviewers is still 2
+    assert_eq!(application.status, ApplicationStatus::Pending);
+}
+
+#[test]
+fn lowering_quorum_approves_applications_reaching_it() {
+    let mut contract = setup();
+    vote(&mut contract, 1, ReviewVote::Approve);
+    set_context(&owner(), ONE_NEAR, APPLICATION_START_MS);
+    contract.admin_set_review_quorum(1);
+    let application = contract.get_application_by_project_id(project(1));
+    assert_eq!(application.status, ApplicationStatus::Approved);
+    assert_eq!(contract.get_approved_applications(None, None).len(), 1);
+}
@@ -0,0 +1,62 @@
+mod common;
+
+use common::*;
+use potlock_pot::*;
+
+fn earmark() -> MatchingPoolEarmark {
+    MatchingPoolEarmark::Projects(vec![project(1)])
+}
+
+/// Pot with 2 approved projects
+fn setup() -> Contract {
+    let mut contract = new_pot(default_pot_args());
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    contract
+}
+
+fn donate_earmarked(contract: &mut Contract, amount: u128) -> Option<DonationExternal> {
+    set_context(&donor(), amount, PUBLIC_ROUND_MS);
+    unwrap_value(contract.donate(
+        None,
+        None,
+        None,
+        Some(true),
+        Some(true),
+        None,
+        None,
+        Some(earmark()),
+    ))
+}
+
+#[test]
+fn earmarked_donations_are_added_to_chef_created_sub_pool() {
+    let mut contract = setup();
+    set_context(&chef(), ONE_NEAR, PUBLIC_ROUND_MS);
+    let sub_pool = contract.chef_create_matching_sub_pool(earmark());
+    assert_eq!(sub_pool.balance.0, 0);
+
+    let donation =
+        donate_earmarked(&mut contract, 2 * ONE_NEAR).expect("donation should be recorded");
+    assert_eq!(donation.sub_pool_id, Some(sub_pool.id));
+    let sub_pool = contract
+        .get_matching_sub_pool(sub_pool.id)
+        .expect("sub-pool should exist");
+    assert_eq!(sub_pool.balance, donation.net_amount);
+    assert_eq!(sub_pool.donations_count, 1);
+}
+
+#[test]
+#[should_panic(expected = "No matching sub-pool exists for this earmark")]
+fn donors_cannot_create_sub_pools() {
+    let mut contract = setup();
+    donate_earmarked(&mut contract, 2 * ONE_NEAR);
+}
+
+#[test]
+#[should_panic(expected = "Only chef, admin or owner can call this method")]
+fn only_chef_or_greater_can_create_sub_pools() {
+    let mut contract = setup();
+    set_context(&donor(), ONE_NEAR, PUBLIC_ROUND_MS);
+    contract.chef_create_matching_sub_pool(earmark());
+}
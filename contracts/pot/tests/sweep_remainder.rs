@@ -0,0 +1,99 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::test_utils::get_logs;
+use near_sdk::{AccountId, PromiseError};
+use potlock_pot::*;
+
+fn remainder_recipient() -> AccountId {
+    "treasury.near".parse().unwrap()
+}
+
+/// After the cooldown period
+const PAYOUTS_MS: u64 = ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1;
+
+/// Pot whose matching pool has been paid out
+fn setup() -> Contract {
+    let mut contract = new_pot(PotArgs {
+        remainder_recipient: Some(remainder_recipient()),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    donate_to_matching_pool(&mut contract, 10 * ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance;
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![PayoutInput {
+            project_id: project(1),
+            amount: matching_pool_balance,
+        }],
+        Some("Single project".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    set_context(&owner(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(None);
+    let payout_id = contract.get_payouts(None, None)[0].id.clone();
+    complete_payout_transfer(&mut contract, &payout_id, true, PAYOUTS_MS);
+    assert!(contract.get_config().all_paid_out);
+    contract
+}
+
+fn assert_swept(contract: &Contract, amount: u128) {
+    assert_eq!(contract.get_config().swept_remainder_amount.0, amount);
+    assert_eq!(
+        get_logs()
+            .iter()
+            .any(|log| log.contains("\"sweep_remainder\"")),
+        amount > 0
+    );
+}
+
+#[test]
+fn remainder_is_transferred_to_remainder_recipient() {
+    let mut contract = setup();
+    set_context(&owner(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_sweep_remainder(None);
+    assert!(transferred_to(&remainder_recipient()) > 0);
+
+    set_callback_context(PAYOUTS_MS, vec![]);
+    contract.sweep_remainder_callback(remainder_recipient(), U128(ONE_NEAR), Ok(()));
+    assert_swept(&contract, ONE_NEAR);
+}
+
+#[test]
+fn failed_transfer_is_not_counted_as_swept() {
+    let mut contract = setup();
+    set_callback_context(PAYOUTS_MS, vec![]);
+    contract.sweep_remainder_callback(
+        remainder_recipient(),
+        U128(ONE_NEAR),
+        Err(PromiseError::Failed),
+    );
+    assert_swept(&contract, 0);
+}
+
+#[test]
+fn rollover_counts_only_recorded_donations() {
+    let mut contract = setup();
+    // the recipient Pot resolves `donate` to the recorded donation
+    let donation = unwrap_value(donate(&mut contract, &donor(), &project(1), ONE_NEAR));
+    set_callback_context(PAYOUTS_MS, vec![]);
+    contract.rollover_remainder_callback(remainder_recipient(), U128(ONE_NEAR), Ok(None));
+    assert_swept(&contract, 0);
+    set_callback_context(PAYOUTS_MS, vec![]);
+    contract.rollover_remainder_callback(remainder_recipient(), U128(ONE_NEAR), Ok(donation));
+    assert_swept(&contract, ONE_NEAR);
+}
+
+#[test]
+fn ft_rollover_counts_only_used_amount() {
+    let mut contract = setup();
+    set_callback_context(PAYOUTS_MS, vec![]);
+    contract.ft_rollover_remainder_callback(
+        remainder_recipient(),
+        U128(ONE_NEAR),
+        Ok(U128(ONE_NEAR / 4)),
+    );
+    assert_swept(&contract, ONE_NEAR / 4);
+}
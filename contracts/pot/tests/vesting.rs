@@ -0,0 +1,100 @@
+mod common;
+
+use common::*;
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use potlock_pot::*;
+
+fn remainder_recipient() -> AccountId {
+    "treasury.near".parse().unwrap()
+}
+
+/// After the cooldown period
+const PAYOUTS_MS: u64 = ROUND_CLOSED_MS + DEFAULT_COOLDOWN_PERIOD_MS + 1;
+const CLIFF_PERIOD_MS: u64 = 1_000;
+const VESTING_PERIOD_MS: u64 = 10_000;
+/// Halfway through the vesting period
+const HALF_VESTED_MS: u64 = PAYOUTS_MS + VESTING_PERIOD_MS / 2;
+
+/// Pot with a 10 NEAR payout to project 1 (& the rest of the matching pool to project 2) that has started vesting
+fn setup() -> (Contract, PayoutId) {
+    let mut contract = new_pot(PotArgs {
+        payout_vesting_schedule: Some(PayoutVestingSchedule {
+            cliff_period_ms: CLIFF_PERIOD_MS,
+            vesting_period_ms: VESTING_PERIOD_MS,
+        }),
+        remainder_recipient: Some(remainder_recipient()),
+        ..default_pot_args()
+    });
+    approve_project(&mut contract, &project(1));
+    approve_project(&mut contract, &project(2));
+    donate_to_matching_pool(&mut contract, 20 * ONE_NEAR);
+    let matching_pool_balance = contract.get_config().matching_pool_balance.0;
+    set_context(&chef(), ONE_NEAR, ROUND_CLOSED_MS);
+    contract.chef_set_payouts(
+        vec![
+            PayoutInput {
+                project_id: project(1),
+                amount: U128(10 * ONE_NEAR),
+            },
+            PayoutInput {
+                project_id: project(2),
+                amount: U128(matching_pool_balance - 10 * ONE_NEAR),
+            },
+        ],
+        Some("Manual payouts".to_string()),
+    );
+    contract.chef_finalize_payouts(None);
+    set_context(&owner(), ONE_NEAR, PAYOUTS_MS);
+    contract.admin_process_payouts(None);
+    let payout = contract
+        .get_payouts(None, None)
+        .into_iter()
+        .find(|payout| payout.project_id == project(1))
+        .unwrap();
+    assert!(payout.vesting.is_some());
+    (contract, payout.id)
+}
+
+fn terminate(contract: &mut Contract, payout_id: &PayoutId, transfer: bool) {
+    set_context(&owner(), ONE_NEAR, HALF_VESTED_MS);
+    contract.admin_terminate_payout_vesting(payout_id.clone(), Some(transfer));
+}
+
+#[test]
+fn unvested_remainder_is_reclaimed_on_termination() {
+    let (mut contract, payout_id) = setup();
+    terminate(&mut contract, &payout_id, false);
+    assert_eq!(
+        contract.get_config().reclaimed_payouts_balance.0,
+        5 * ONE_NEAR
+    );
+    assert_eq!(transferred_to(&remainder_recipient()), 0);
+
+    // project keeps what had vested at termination, and nothing more
+    set_context(&project(1), 0, PAYOUTS_MS + VESTING_PERIOD_MS);
+    assert_eq!(
+        contract.get_withdrawable_vested_amount(project(1)).0,
+        5 * ONE_NEAR
+    );
+    assert_eq!(contract.withdraw_vested().0, 5 * ONE_NEAR);
+}
+
+#[test]
+fn unvested_remainder_can_be_transferred_to_remainder_recipient() {
+    let (mut contract, payout_id) = setup();
+    terminate(&mut contract, &payout_id, true);
+    assert_eq!(transferred_to(&remainder_recipient()), 5 * ONE_NEAR);
+    assert_eq!(
+        contract.get_config().reclaimed_payouts_balance.0,
+        5 * ONE_NEAR
+    );
+}
+
+#[test]
+#[should_panic(expected = "Vesting has already been terminated")]
+fn vesting_cannot_be_terminated_twice() {
+    let (mut contract, payout_id) = setup();
+    terminate(&mut contract, &payout_id, false);
+    terminate(&mut contract, &payout_id, false);
+}
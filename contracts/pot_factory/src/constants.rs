@@ -12,9 +12,11 @@ pub const EVENT_JSON_PREFIX: &str = "EVENT_JSON:";
 // Pot args constraints
 pub const MAX_POT_NAME_LENGTH: usize = 64;
 pub const MAX_POT_DESCRIPTION_LENGTH: usize = 256;
-pub const MAX_MAX_PROJECTS: u32 = 100; // TODO: figure out actual limit based on gas
+pub const MAX_MAX_PROJECTS: u32 = 1000; // must match Pot contract
 pub const MAX_REFERRAL_FEE_MATCHING_POOL_BASIS_POINTS: u32 = 1000; // 10%
 pub const MAX_REFERRAL_FEE_PUBLIC_ROUND_BASIS_POINTS: u32 = 1000; // 10%
 pub const MAX_CHEF_FEE_BASIS_POINTS: u32 = 1000; // 10%
+pub const MAX_CUSTOM_SYBIL_CHECKS: usize = 10; // must match Pot contract
+pub const MAX_ACCEPTED_CURRENCIES: usize = 10; // must match Pot contract
 pub const MIN_COOLDOWN_PERIOD_MS: u64 = ONE_WEEK_MS;
 pub const DEFAULT_COOLDOWN_PERIOD_MS: u64 = ONE_WEEK_MS;
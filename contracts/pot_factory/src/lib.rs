@@ -156,6 +156,48 @@ pub struct CustomSybilCheck {
     weight: SybilProviderWeight,
 }
 
+/// Ephemeral-only (used in application_schema for setting on Pot deployment, but not stored in this contract; rather, stored in Pot contract)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub enum ApplicationQuestionType {
+    Text,
+    Number,
+    Boolean,
+    Url,
+    AccountId,
+    Select { options: Vec<String> },
+}
+
+/// Ephemeral-only (used in application_schema for setting on Pot deployment, but not stored in this contract; rather, stored in Pot contract)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ApplicationQuestion {
+    key: String,
+    prompt: String,
+    question_type: ApplicationQuestionType,
+    required: bool,
+    max_length: Option<u32>,
+}
+
+/// Ephemeral-only (used in donation_limits for setting on Pot deployment, but not stored in this contract; rather, stored in Pot contract)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DonationLimits {
+    min_amount: Option<U128>,
+    max_amount: Option<U128>,
+    max_per_donor_per_project: Option<U128>,
+    max_per_donor: Option<U128>,
+    max_per_project: Option<U128>,
+}
+
+/// Ephemeral-only (used in payout_vesting_schedule for setting on Pot deployment, but not stored in this contract; rather, stored in Pot contract)
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct PayoutVestingSchedule {
+    cliff_period_ms: u64,
+    vesting_period_ms: u64,
+}
+
 #[near_bindgen]
 impl Contract {
     #[init]
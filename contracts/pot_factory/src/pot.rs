@@ -41,15 +41,29 @@ pub struct PotArgs {
     pub owner: Option<AccountId>,
     pub admins: Option<Vec<AccountId>>,
     pub chef: Option<AccountId>,
+    pub reviewers: Option<Vec<AccountId>>,
     pub pot_name: String,
     pub pot_description: String,
     pub max_projects: u32,
+    pub base_currency: Option<AccountId>,
+    pub accepted_currencies: Option<Vec<AccountId>>,
+    pub price_oracle_provider: Option<ProviderId>,
     pub application_start_ms: TimestampMs,
     pub application_end_ms: TimestampMs,
+    pub application_schema: Option<Vec<ApplicationQuestion>>,
+    pub review_quorum: Option<u32>,
     pub public_round_start_ms: TimestampMs,
     pub public_round_end_ms: TimestampMs,
     pub min_matching_pool_donation_amount: Option<U128>,
+    pub donation_limits: Option<DonationLimits>,
+    pub escrow_public_donations: Option<bool>,
+    pub pairwise_match_threshold: Option<U128>,
     pub cooldown_period_ms: Option<u64>,
+    pub payouts_challenge_bond: Option<U128>,
+    pub payouts_claimable: Option<bool>,
+    pub claim_deadline_ms: Option<TimestampMs>,
+    pub payout_vesting_schedule: Option<PayoutVestingSchedule>,
+    pub remainder_recipient: Option<AccountId>,
     pub registry_provider: Option<ProviderId>,
     pub sybil_wrapper_provider: Option<ProviderId>,
     pub custom_sybil_checks: Option<Vec<CustomSybilCheck>>,
@@ -137,7 +151,7 @@ impl Contract {
             .deploy_contract(POT_WASM_CODE.to_vec())
             .function_call(
                 "new".to_string(),
-                serde_json::to_vec(&pot_args).unwrap(),
+                serde_json::to_vec(&json!({ "pot_args": pot_args })).unwrap(),
                 0,
                 XCC_GAS,
             )
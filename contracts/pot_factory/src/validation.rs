@@ -59,6 +59,12 @@ pub(crate) fn assert_valid_pot_timestamps(args: &PotArgs) {
         args.public_round_start_ms < args.public_round_end_ms,
         "Public round start must be before public round end"
     );
+    if let Some(claim_deadline_ms) = args.claim_deadline_ms {
+        assert!(
+            claim_deadline_ms > args.public_round_end_ms,
+            "Claim deadline must be after public round end"
+        );
+    }
 }
 
 pub(crate) fn assert_valid_provider_id(provider_id: &ProviderId) {
@@ -91,10 +97,25 @@ pub(crate) fn assert_valid_pot_args(args: &PotArgs) {
     if let Some(provider_id) = &args.registry_provider {
         assert_valid_provider_id(provider_id);
     }
+    if let Some(accepted_currencies) = &args.accepted_currencies {
+        assert!(
+            accepted_currencies.len() <= MAX_ACCEPTED_CURRENCIES,
+            "Accepted currencies cannot exceed {}",
+            MAX_ACCEPTED_CURRENCIES
+        );
+    }
+    if let Some(provider_id) = &args.price_oracle_provider {
+        assert_valid_provider_id(provider_id);
+    }
     if let Some(provider_id) = &args.sybil_wrapper_provider {
         assert_valid_provider_id(provider_id);
     }
     if let Some(custom_sybil_checks) = &args.custom_sybil_checks {
+        assert!(
+            custom_sybil_checks.len() <= MAX_CUSTOM_SYBIL_CHECKS,
+            "Custom sybil checks cannot exceed {}",
+            MAX_CUSTOM_SYBIL_CHECKS
+        );
         for check in custom_sybil_checks {
             assert_valid_provider_id(&ProviderId::new(
                 check.contract_id.clone().to_string(),
@@ -0,0 +1,11 @@
+#!/bin/sh
+
+echo ">> Building PriceOracle contract"
+
+set -e
+
+export CARGO_TARGET_DIR=target
+RUSTFLAGS='-C link-arg=-s' cargo build --target wasm32-unknown-unknown --release
+mkdir -p ./out
+cp target/wasm32-unknown-unknown/release/*.wasm ./out/main.wasm
+echo ">> Finished Building PriceOracle contract"
\ No newline at end of file
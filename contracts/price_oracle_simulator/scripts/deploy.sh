@@ -0,0 +1,11 @@
+#!/bin/sh
+
+if [ $? -ne 0 ]; then
+  echo ">> Error building PriceOracle contract"
+  exit 1
+fi
+
+echo ">> Deploying PriceOracle contract!"
+
+# https://docs.near.org/tools/near-cli#near-dev-deploy
+near dev-deploy --wasmFile ./out/main.wasm
\ No newline at end of file
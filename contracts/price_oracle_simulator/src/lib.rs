@@ -0,0 +1,98 @@
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::UnorderedMap;
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, log, near_bindgen, require, AccountId, Balance, BorshStorageKey, Promise};
+
+pub mod utils;
+pub use crate::utils::*;
+
+/// Price of one unit (smallest denomination) of a currency, expressed in units of a base currency as `multiplier / 10^decimals`
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Price {
+    pub multiplier: U128,
+    pub decimals: u8,
+}
+
+/// PriceOracle Contract
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct Contract {
+    prices: UnorderedMap<(AccountId, AccountId), Price>,
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub enum VersionedContract {
+    Current(Contract),
+}
+
+/// Convert VersionedContract to Contract
+impl From<VersionedContract> for Contract {
+    fn from(contract: VersionedContract) -> Self {
+        match contract {
+            VersionedContract::Current(current) => current,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshStorageKey)]
+pub enum StorageKey {
+    Prices,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            prices: UnorderedMap::new(StorageKey::Prices),
+        }
+    }
+
+    #[payable]
+    pub fn set_price(
+        &mut self,
+        currency: AccountId,
+        base_currency: AccountId,
+        multiplier: U128,
+        decimals: u8,
+    ) {
+        let initial_storage_usage = env::storage_usage();
+        self.prices.insert(
+            &(currency, base_currency),
+            &Price {
+                multiplier,
+                decimals,
+            },
+        );
+        // Refund any unused deposit after storage cost is covered
+        refund_deposit(initial_storage_usage);
+    }
+
+    pub fn remove_price(&mut self, currency: AccountId, base_currency: AccountId) {
+        let initial_storage_usage = env::storage_usage();
+        self.prices.remove(&(currency, base_currency));
+        // Refund user for storage freed
+        refund_deposit(initial_storage_usage);
+    }
+
+    pub fn get_price(&self, currency: AccountId, base_currency: AccountId) -> Price {
+        self.prices
+            .get(&(currency.clone(), base_currency.clone()))
+            .unwrap_or_else(|| {
+                env::panic_str(&format!(
+                    "No price set for {} in {}",
+                    currency, base_currency
+                ))
+            })
+    }
+}
+
+impl Default for Contract {
+    fn default() -> Self {
+        Self {
+            prices: UnorderedMap::new(StorageKey::Prices),
+        }
+    }
+}
@@ -1,5 +1,49 @@
 use crate::*;
 
+/// features paused by owner/admin
+pub(crate) fn log_pause_event(paused_by: &AccountId, features: &[PausableFeature]) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "pause",
+                "data": [
+                    {
+                        "paused_by": paused_by,
+                        "features": features,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
+/// features unpaused by owner/admin
+pub(crate) fn log_unpause_event(unpaused_by: &AccountId, features: &[PausableFeature]) {
+    env::log_str(
+        format!(
+            "{}{}",
+            EVENT_JSON_PREFIX,
+            json!({
+                "standard": "potlock",
+                "version": "1.0.0",
+                "event": "unpause",
+                "data": [
+                    {
+                        "unpaused_by": unpaused_by,
+                        "features": features,
+                    }
+                ]
+            })
+        )
+        .as_ref(),
+    );
+}
+
 /// source metadata update
 pub(crate) fn log_set_source_metadata_event(source_metadata: &ContractSourceMetadata) {
     env::log_str(
@@ -28,6 +28,14 @@ impl Contract {
         self.assert_at_least_one_yocto();
     }
 
+    pub(crate) fn assert_not_paused(&self, feature: PausableFeature) {
+        assert!(
+            !self.paused_features.contains(&feature),
+            "{:?} are currently paused",
+            feature
+        );
+    }
+
     pub(crate) fn assert_project_exists(&self, project_id: &AccountId) {
         assert!(
             self.projects_by_id.get(project_id).is_some(),
@@ -11,6 +11,7 @@ pub mod constants;
 pub mod events;
 pub mod internal;
 pub mod owner;
+pub mod pause;
 pub mod projects;
 pub mod source;
 pub mod utils;
@@ -19,6 +20,7 @@ pub use crate::constants::*;
 pub use crate::events::*;
 pub use crate::internal::*;
 pub use crate::owner::*;
+pub use crate::pause::*;
 pub use crate::projects::*;
 pub use crate::source::*;
 pub use crate::utils::*;
@@ -39,7 +41,25 @@ pub struct ContractV1 {
     projects_by_id: LookupMap<ProjectId, VersionedProjectInternal>,
 }
 
-/// CURRENT Registry Contract (v2.0.0)
+/// OLD (v2) Registry Contract (v2.0.0)
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractV2 {
+    owner: AccountId,
+    admins: UnorderedSet<AccountId>,
+    _deprecated_project_ids: UnorderedSet<ProjectId>,
+    _deprecated_projects_by_id: LookupMap<ProjectId, VersionedProjectInternal>,
+    projects_by_id: UnorderedMap<ProjectId, VersionedProjectInternal>,
+    pending_project_ids: UnorderedSet<ProjectId>,
+    approved_project_ids: UnorderedSet<ProjectId>,
+    rejected_project_ids: UnorderedSet<ProjectId>,
+    graylisted_project_ids: UnorderedSet<ProjectId>,
+    blacklisted_project_ids: UnorderedSet<ProjectId>,
+    contract_source_metadata: LazyOption<VersionedContractSourceMetadata>,
+    default_project_status: ProjectStatus,
+}
+
+/// CURRENT Registry Contract (v3.0.0)
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize)]
 pub struct Contract {
@@ -67,6 +87,8 @@ pub struct Contract {
     contract_source_metadata: LazyOption<VersionedContractSourceMetadata>,
     /// Default status when project registers
     default_project_status: ProjectStatus,
+    /// Features (e.g. registrations) currently paused by owner/admin
+    paused_features: UnorderedSet<PausableFeature>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
@@ -95,6 +117,7 @@ pub enum StorageKey {
     GraylistedProjectIds,
     BlacklistedProjectIds,
     SourceMetadata,
+    PausedFeatures,
 }
 
 /// Contract configuration
@@ -109,6 +132,7 @@ pub struct ContractConfig {
     pub rejected_project_count: u64,
     pub graylisted_project_count: u64,
     pub blacklisted_project_count: u64,
+    pub paused_features: Vec<PausableFeature>,
 }
 
 #[near_bindgen]
@@ -135,6 +159,7 @@ impl Contract {
                 Some(&VersionedContractSourceMetadata::Current(source_metadata)),
             ),
             default_project_status: ProjectStatus::Approved,
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
         }
     }
 
@@ -148,6 +173,7 @@ impl Contract {
             rejected_project_count: self.rejected_project_ids.len(),
             graylisted_project_count: self.graylisted_project_ids.len(),
             blacklisted_project_count: self.blacklisted_project_ids.len(),
+            paused_features: self.paused_features.to_vec(),
         }
     }
 
@@ -219,6 +245,28 @@ impl Contract {
     //         default_project_status: ProjectStatus::Approved,
     //     }
     // }
+
+    // this is the initFunction used in upgrade from v2.0.0 to v3.0.0
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old_state: ContractV2 = env::state_read().expect("state read failed");
+        Self {
+            owner: old_state.owner,
+            admins: old_state.admins,
+            _deprecated_project_ids: old_state._deprecated_project_ids,
+            _deprecated_projects_by_id: old_state._deprecated_projects_by_id,
+            projects_by_id: old_state.projects_by_id,
+            pending_project_ids: old_state.pending_project_ids,
+            approved_project_ids: old_state.approved_project_ids,
+            rejected_project_ids: old_state.rejected_project_ids,
+            graylisted_project_ids: old_state.graylisted_project_ids,
+            blacklisted_project_ids: old_state.blacklisted_project_ids,
+            contract_source_metadata: old_state.contract_source_metadata,
+            default_project_status: old_state.default_project_status,
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
+        }
+    }
 }
 
 impl Default for Contract {
@@ -236,6 +284,7 @@ impl Default for Contract {
             blacklisted_project_ids: UnorderedSet::new(StorageKey::BlacklistedProjectIds),
             contract_source_metadata: LazyOption::new(StorageKey::SourceMetadata, None),
             default_project_status: ProjectStatus::Approved,
+            paused_features: UnorderedSet::new(StorageKey::PausedFeatures),
         }
     }
 }
@@ -0,0 +1,42 @@
+use crate::*;
+
+/// Features of the Registry that can be paused independently by owner/admin.
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub enum PausableFeature {
+    /// Project registrations
+    Registrations,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[payable]
+    pub fn admin_pause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.insert(feature);
+        }
+        log_pause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage);
+    }
+
+    #[payable]
+    pub fn admin_unpause(&mut self, features: Vec<PausableFeature>) {
+        self.assert_admin_or_greater();
+        let initial_storage_usage = env::storage_usage();
+        for feature in features.iter() {
+            self.paused_features.remove(feature);
+        }
+        log_unpause_event(&env::predecessor_account_id(), &features);
+        refund_deposit(initial_storage_usage);
+    }
+
+    pub fn get_paused_features(&self) -> Vec<PausableFeature> {
+        self.paused_features.to_vec()
+    }
+
+    pub fn is_paused(&self, feature: PausableFeature) -> bool {
+        self.paused_features.contains(&feature)
+    }
+}
@@ -86,6 +86,7 @@ pub struct ProjectExternal {
 impl Contract {
     #[payable]
     pub fn register(&mut self, _project_id: Option<AccountId>) -> ProjectExternal {
+        self.assert_not_paused(PausableFeature::Registrations);
         let initial_storage_usage = env::storage_usage();
 
         // _project_id can only be specified by admin; otherwise, it is the caller
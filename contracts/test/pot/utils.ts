@@ -113,7 +113,7 @@ export const initializeContract = async (
 ) => {
   return callSelf({
     methodName: WRITE_METHODS.NEW,
-    args: initializeArgs,
+    args: { pot_args: initializeArgs },
     attachedDeposit: NO_DEPOSIT,
   });
 };
@@ -360,13 +360,14 @@ export const getPayouts = async (): Promise<Payout[]> => {
 
 export const chefSetPayouts = async (
   chefAccount: Account,
-  payouts: PayoutInput[]
+  payouts: PayoutInput[],
+  justification?: string
 ) => {
   return contractCall({
     callerAccount: chefAccount,
     contractId: _contractId,
     methodName: WRITE_METHODS.CHEF_SET_PAYOUTS,
-    args: { payouts },
+    args: { payouts, justification },
   });
 };
 
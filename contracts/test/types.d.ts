@@ -20,6 +20,7 @@ enum ApplicationStatus {
   InReview = "InReview",
   Approved = "Approved",
   Rejected = "Rejected",
+  Disqualified = "Disqualified",
 }
 
 interface Project {
@@ -36,17 +37,60 @@ interface CustomSybilCheck {
   weight: number;
 }
 
+type ApplicationQuestionType =
+  | "Text"
+  | "Number"
+  | "Boolean"
+  | "Url"
+  | "AccountId"
+  | { Select: { options: string[] } };
+
+interface ApplicationQuestion {
+  key: string;
+  prompt: string;
+  question_type: ApplicationQuestionType;
+  required: boolean;
+  max_length: number | null;
+}
+
+interface ApplicationAnswer {
+  key: string;
+  value: string;
+}
+
+interface DonationLimits {
+  min_amount: string | null;
+  max_amount: string | null;
+  max_per_donor_per_project: string | null;
+  max_per_donor: string | null;
+  max_per_project: string | null;
+}
+
 interface PotArgs {
   owner?: AccountId;
   admins?: AccountId[];
   chef?: AccountId;
+  reviewers?: AccountId[];
   pot_name: String;
   pot_description: String;
   max_projects: number;
+  base_currency?: AccountId;
+  accepted_currencies?: AccountId[];
+  price_oracle_provider?: ProviderId;
   application_start_ms: TimestampMs;
   application_end_ms: TimestampMs;
+  application_schema?: ApplicationQuestion[];
+  review_quorum?: number;
   public_round_start_ms: TimestampMs;
   public_round_end_ms: TimestampMs;
+  donation_limits?: DonationLimits;
+  escrow_public_donations?: boolean;
+  pairwise_match_threshold?: string;
+  payouts_challenge_bond?: string;
+  payouts_claimable?: boolean;
+  claim_deadline_ms?: TimestampMs;
+  payout_vesting_schedule?: PayoutVestingSchedule;
+  remainder_recipient?: AccountId;
   registry_provider?: ProviderId;
   sybil_wrapper_provider?: ProviderId;
   custom_sybil_checks?: CustomSybilCheck[];
@@ -56,12 +100,21 @@ interface PotArgs {
   chef_fee_basis_points: number;
 }
 
+type PausableFeature = "Donations" | "Applications" | "Payouts";
+
 interface PotConfig extends PotArgs {
   deployed_by: AccountId;
   matching_pool_balance: string;
   donations_balance: string;
+  escrowed_donations_balance: string;
+  forfeited_challenge_bonds: string;
+  reclaimed_payouts_balance: string;
+  swept_remainder_amount: string;
+  payouts_review_required: boolean;
   cooldown_end_ms: TimestampMs | null;
   all_paid_out: boolean;
+  paused_features: PausableFeature[];
+  trusted_intermediaries: AccountId[];
 }
 
 interface Pot {
@@ -86,10 +139,34 @@ interface PotDeployerConfig {
 
 interface Application {
   project_id: ProjectId;
+  message: string | null;
+  answers: ApplicationAnswer[];
   status: ApplicationStatus;
   submitted_at: TimestampMs;
   updated_at: TimestampMs | null;
   review_notes: string | null;
+  approve_votes_count: number;
+  reject_votes_count: number;
+  category: string | null;
+  disqualification_reason: string | null;
+  payout_recipient: AccountId | null;
+}
+
+type ReviewVote = "Approve" | "Reject";
+
+interface ApplicationVote {
+  reviewer_id: AccountId;
+  vote: ReviewVote;
+  notes: string | null;
+  voted_at: TimestampMs;
+}
+
+interface ApplicationReview {
+  reviewer_id: AccountId;
+  previous_status: ApplicationStatus;
+  status: ApplicationStatus;
+  notes: string;
+  reviewed_at: TimestampMs;
 }
 
 /// Patron donation; no application specified
@@ -109,12 +186,47 @@ interface PatronDonation {
 interface Donation {
   id: number;
   donor_id: AccountId;
+  sent_by: AccountId;
   total_amount: string;
   message: string | null;
   donated_at: TimestampMs;
   project_id: ProjectId;
   protocol_fee: string;
   amount_after_fees: string;
+  currency: DonationCurrency | null; // null if donated in base currency
+  normalized_total_amount: string; // in base currency
+  normalized_net_amount: string; // in base currency
+}
+
+interface DonationCurrency {
+  currency: AccountId; // "near" or NEP-141 contract ID
+  price: { multiplier: string; decimals: number };
+}
+
+interface BatchDonationItem {
+  project_id: ProjectId;
+  amount: string;
+  message: string | null;
+}
+
+type MatchingPoolEarmark = { Projects: ProjectId[] } | { Category: string };
+
+interface MatchingSubPool {
+  id: number;
+  earmark: MatchingPoolEarmark;
+  balance: string;
+  donations_count: number;
+}
+
+interface ProjectStats {
+  project_id: ProjectId;
+  total_donations: string;
+  net_donations: string;
+  donations_count: number;
+  unique_donors: number;
+  sum_of_sqrt_contributions: string;
+  total_referrer_fees: string;
+  referred_donations_count: number;
 }
 
 /// Project payout
@@ -125,6 +237,29 @@ interface Payout {
   donations_amount: string;
   amount_total: string;
   paid_at: TimestampMs | null;
+  milestone: PayoutMilestone | null;
+  clawed_back_at: TimestampMs | null;
+  escrowed_donations: boolean;
+  vesting: PayoutVesting | null;
+}
+
+interface PayoutVestingSchedule {
+  cliff_period_ms: number;
+  vesting_period_ms: number;
+}
+
+interface PayoutVesting {
+  start_at: TimestampMs;
+  cliff_at: TimestampMs;
+  end_at: TimestampMs;
+  withdrawn_amount: string;
+  terminated_at: TimestampMs | null;
+}
+
+interface PayoutMilestone {
+  description: string;
+  due_at: TimestampMs;
+  completed_at: TimestampMs | null;
 }
 
 interface PayoutInput {